@@ -26,6 +26,8 @@ pub fn derive_dice(input: TokenStream) -> TokenStream {
 
     let expanded = quote! {
         impl Dice for #name {
+            const FACES: u64 = #count;
+
             fn nth(n: u64) -> Self {
                 assert!(n < #count);
                 match n {
@@ -34,8 +36,10 @@ pub fn derive_dice(input: TokenStream) -> TokenStream {
                 }
             }
 
-            fn faces() -> u64 {
-                #count
+            fn face(&self) -> u64 {
+                match self {
+                    #(Self::#variants => #ns),*
+                }
             }
         }
     };
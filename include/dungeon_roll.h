@@ -0,0 +1,59 @@
+/*
+ * C bindings for the Dungeon Roll rules engine.
+ *
+ * Games are played one delve at a time and rewarded with the XP the hero
+ * banks. Commands are the JSON objects accepted by the `--json` mode, e.g.
+ * {"command":"fight","ally":0,"monster":2}. No function panics across the
+ * boundary, failures are reported through the return value.
+ */
+
+#ifndef DUNGEON_ROLL_H
+#define DUNGEON_ROLL_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define DR_OK 0
+#define DR_NULL -1
+#define DR_INVALID_UTF8 -2
+#define DR_MALFORMED -3
+#define DR_ILLEGAL -4
+#define DR_BUFFER_TOO_SMALL -5
+#define DR_PANIC -6
+
+typedef struct DrGame DrGame;
+
+/* Creates a game whose dice only depend on `seed`, returns NULL on failure */
+DrGame *dr_game_new(uint64_t seed);
+
+/* Frees a game, NULL is ignored */
+void dr_game_free(DrGame *game);
+
+/* Starts a new delve with `seed` */
+int32_t dr_game_reset(DrGame *game, uint64_t seed);
+
+/* Plays a JSON command, `reward` and `done` may be NULL */
+int32_t dr_game_step(DrGame *game, const char *command, float *reward, bool *done);
+
+/* Number of floats in an observation */
+size_t dr_observation_size(void);
+
+/* Writes the observation to `out`, which holds `len` floats */
+int32_t dr_game_observation(const DrGame *game, float *out, size_t len);
+
+/* Observable state and legal commands as JSON, free with dr_string_free */
+char *dr_game_state(const DrGame *game);
+
+/* Frees a string returned by dr_game_state, NULL is ignored */
+void dr_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,86 @@
+use crate::locale::Language;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fs, io, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    DragonSlayer,
+    DeepDelver,
+    NoChampions,
+    MiracleWorker,
+    LastOneOut,
+    Hoarder,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 6] = [
+        Achievement::DragonSlayer,
+        Achievement::DeepDelver,
+        Achievement::NoChampions,
+        Achievement::MiracleWorker,
+        Achievement::LastOneOut,
+        Achievement::Hoarder,
+    ];
+
+    /// Key of `field` of the achievement in the catalogs
+    fn key(self, field: &str) -> String {
+        let name = match self {
+            Achievement::DragonSlayer => "dragon_slayer",
+            Achievement::DeepDelver => "deep_delver",
+            Achievement::NoChampions => "no_champions",
+            Achievement::MiracleWorker => "miracle_worker",
+            Achievement::LastOneOut => "last_one_out",
+            Achievement::Hoarder => "hoarder",
+        };
+        format!("achievement.{name}.{field}")
+    }
+
+    pub fn name(self, language: Language) -> Cow<'static, str> {
+        language.text(&self.key("name"))
+    }
+
+    pub fn describe(self, language: Language) -> Cow<'static, str> {
+        language.text(&self.key("describe"))
+    }
+}
+
+/// Every achievement unlocked on this machine
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Achievements {
+    #[serde(default)]
+    unlocked: Vec<Achievement>,
+}
+
+impl Achievements {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Achievements::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn store<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let s = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    /// Unlocks `achievement`, returns false if it already was
+    pub fn unlock(&mut self, achievement: Achievement) -> bool {
+        if self.is_unlocked(achievement) {
+            return false;
+        }
+
+        self.unlocked.push(achievement);
+        true
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{keymap::Keymap, rules::Rules, scenario::Scenario, theme::Theme};
+use std::{env, path::PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frontend {
+    Terminal,
+    Text,
+    Json,
+}
+
+pub struct Args {
+    pub rules: Rules,
+    pub keymap: Keymap,
+    /// Override the config file
+    pub theme: Option<Theme>,
+    pub animations: Option<bool>,
+    pub roster: Option<PathBuf>,
+    /// Holds the roster, unless `roster` is given, and every other file
+    pub data_dir: Option<PathBuf>,
+    pub scenario: Option<Scenario>,
+    pub seed: Option<u64>,
+    pub serve: Option<String>,
+    pub connect: Option<String>,
+    pub seat: Option<usize>,
+    pub spectate: bool,
+    pub frontend: Frontend,
+    pub daily: bool,
+    pub tutorial: bool,
+}
+
+impl Args {
+    pub fn parse() -> Result<Self, String> {
+        let mut args = env::args().skip(1);
+        let mut parsed = Self {
+            rules: Rules::default(),
+            keymap: Keymap::default(),
+            theme: None,
+            animations: None,
+            roster: None,
+            data_dir: None,
+            scenario: None,
+            seed: None,
+            serve: None,
+            connect: None,
+            seat: None,
+            spectate: false,
+            frontend: Frontend::Terminal,
+            daily: false,
+            tutorial: false,
+        };
+        let mut custom_rules = false;
+        // loaded once the rules it's checked against are known
+        let mut scenario = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--rules" => {
+                    let path = args.next().ok_or("--rules requires a path")?;
+                    parsed.rules = Rules::load(path).map_err(|e| e.to_string())?;
+                    custom_rules = true;
+                }
+                "--keymap" => {
+                    let keymap = args.next().ok_or("--keymap requires a preset or a path")?;
+                    parsed.keymap = match Keymap::preset(&keymap) {
+                        Some(preset) => preset,
+                        None => Keymap::load(keymap).map_err(|e| e.to_string())?,
+                    };
+                }
+                "--theme" => {
+                    let name = args.next().ok_or("--theme requires a name")?;
+                    let theme = Theme::from_name(&name).ok_or_else(|| {
+                        let names = Theme::ALL.map(Theme::name).join(", ");
+                        format!("unknown theme {name:?}, expected one of {names}")
+                    })?;
+                    parsed.theme = Some(theme);
+                }
+                "--no-animations" => parsed.animations = Some(false),
+                "--scenario" => {
+                    scenario = Some(args.next().ok_or("--scenario requires a path")?);
+                }
+                "--roster" => {
+                    let path = args.next().ok_or("--roster requires a path")?;
+                    parsed.roster = Some(path.into());
+                }
+                "--data-dir" => {
+                    let path = args.next().ok_or("--data-dir requires a path")?;
+                    parsed.data_dir = Some(path.into());
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed requires a number")?;
+                    parsed.seed = Some(seed.parse().map_err(|_| "--seed requires a number")?);
+                }
+                "--serve" => parsed.serve = Some(args.next().ok_or("--serve requires an address")?),
+                "--connect" => {
+                    parsed.connect = Some(args.next().ok_or("--connect requires an address")?);
+                }
+                "--seat" => {
+                    let seat = args.next().ok_or("--seat requires a number")?;
+                    parsed.seat = Some(seat.parse().map_err(|_| "--seat requires a number")?);
+                }
+                "--spectate" => parsed.spectate = true,
+                "--text" | "--json" if parsed.frontend != Frontend::Terminal => {
+                    return Err("only one of --text and --json can be used".to_string());
+                }
+                "--text" => parsed.frontend = Frontend::Text,
+                "--json" => parsed.frontend = Frontend::Json,
+                "--daily" => parsed.daily = true,
+                "--tutorial" => parsed.tutorial = true,
+                _ => return Err(format!("Unknown argument: {arg}")),
+            }
+        }
+
+        if let Some(path) = scenario {
+            let scenario = Scenario::load(path, &parsed.rules).map_err(|e| e.to_string())?;
+            parsed.scenario = Some(scenario);
+        }
+
+        parsed.validate(custom_rules)?;
+
+        Ok(parsed)
+    }
+
+    /// Rejects options that can't be combined
+    fn validate(&self, custom_rules: bool) -> Result<(), String> {
+        if [
+            self.serve.is_some(),
+            self.connect.is_some(),
+            self.frontend != Frontend::Terminal,
+            self.daily,
+            self.tutorial,
+        ]
+        .iter()
+        .filter(|b| **b)
+        .count()
+            > 1
+        {
+            return Err(
+                "only one of --serve, --connect, --text, --json, --daily and --tutorial can be used"
+                    .to_string(),
+            );
+        }
+
+        if self.scenario.is_some() && (self.serve.is_some() || self.connect.is_some()) {
+            return Err("scenarios can't be played over the network".to_string());
+        }
+
+        if self.tutorial && (custom_rules || self.seed.is_some() || self.scenario.is_some()) {
+            return Err("the tutorial always plays the same delve".to_string());
+        }
+
+        if self.daily && (custom_rules || self.seed.is_some() || self.scenario.is_some()) {
+            return Err("the daily challenge uses the standard rules and its own seed".to_string());
+        }
+
+        if (self.spectate || self.seat.is_some()) && self.connect.is_none() {
+            return Err("--seat and --spectate require --connect".to_string());
+        }
+
+        if self.spectate && self.seat.is_some() {
+            return Err("spectators don't take a seat".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{locale::Language, theme::Theme};
+use serde::Deserialize;
+use std::{fs, io, path::Path};
+
+/// Preferences that apply to every game, command line arguments take
+/// precedence over them
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub theme: Theme,
+    /// Whether dice tumble for a moment when rolled
+    pub animations: bool,
+    /// Taken from `LANG` if unset
+    pub language: Option<Language>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            animations: true,
+            language: None,
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config at `path`, falling back to the defaults if there is
+    /// no config file
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language.unwrap_or_else(Language::from_env)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::hero::Type;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Converts days since the unix epoch to a (year, month, day) UTC date
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Today's challenge, the same for everyone until midnight UTC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Daily {
+    pub date: String,
+    pub seed: u64,
+}
+
+impl Daily {
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() / 86_400);
+        Self::from_days(days)
+    }
+
+    /// The challenge of the day `days` after the unix epoch
+    pub fn from_days(days: u64) -> Self {
+        let (year, month, day) = civil_from_days(days);
+
+        Self {
+            date: format!("{year:04}-{month:02}-{day:02}"),
+            seed: year * 10_000 + month * 100 + day,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    pub date: String,
+    pub seed: u64,
+    pub hero: Type,
+    pub score: u64,
+}
+
+/// Every daily challenge played on this machine
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Records {
+    #[serde(default)]
+    runs: Vec<Run>,
+}
+
+impl Records {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Records::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn store<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let s = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+
+    pub fn find(&self, date: &str) -> Option<&Run> {
+        self.runs.iter().find(|r| r.date == date)
+    }
+
+    /// Adds `run`, replacing any earlier record of the same day
+    pub fn record(&mut self, run: Run) {
+        self.runs.retain(|r| r.date != run.date);
+        self.runs.push(run);
+    }
+}
@@ -1,3 +1,6 @@
+use crate::{locale::Language, theme::Theme};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use tui::{
     style::{Color, Modifier, Style},
     text::{Span, Spans, Text},
@@ -10,27 +13,100 @@ lazy_static! {
 }
 
 pub trait Dice {
+    const FACES: u64;
+
     fn nth(n: u64) -> Self;
-    fn faces() -> u64;
+    fn faces() -> u64 {
+        Self::FACES
+    }
+    /// Inverse of `nth`
+    fn face(&self) -> u64;
 }
 
 pub trait Render {
-    fn style(&self) -> Style;
+    fn style(&self, theme: Theme) -> Style;
 
+    /// Distinct from the other faces of the die, for when there's no room for
+    /// a sprite
     fn symbol(&self) -> &'static str;
 
-    fn render(&self) -> Text {
-        Text::styled(self.symbol(), self.style())
+    /// Drawn in the middle of the face, one cell wide
+    fn icon(&self) -> &'static str;
+
+    /// Names the face in catalog keys and in the links of catalog text
+    fn id(&self) -> &'static str;
+
+    fn name(&self, language: Language) -> Cow<'static, str> {
+        language.text(&self.key("name"))
+    }
+
+    fn render(&self, theme: Theme) -> Text {
+        Text::styled(self.symbol(), self.style(theme))
+    }
+
+    /// The face as large as fits in `width` by `height`, from a boxed icon
+    /// with the name below it down to the bare symbol
+    #[allow(clippy::non_ascii_literal)]
+    fn sprite(&self, theme: Theme, language: Language, width: u16, height: u16) -> Text<'static> {
+        let style = self.style(theme);
+        let lines = if width >= 11 && height >= 5 {
+            vec![
+                "╭─────────╮".to_string(),
+                format!("│{:^9}│", self.icon()),
+                "│         │".to_string(),
+                format!("│{:^9.9}│", self.name(language)),
+                "╰─────────╯".to_string(),
+            ]
+        } else if width >= 5 && height >= 3 {
+            vec![
+                "╭───╮".to_string(),
+                format!("│ {} │", self.icon()),
+                "╰───╯".to_string(),
+            ]
+        } else {
+            return Text::styled(self.symbol(), style);
+        };
+
+        lines
+            .into_iter()
+            .map(|l| Spans::from(Span::styled(l, style)))
+            .collect::<Vec<_>>()
+            .into()
     }
 
-    fn combat_info(&self) -> Spans;
+    /// Catalog key of one of the texts about the face
+    fn key(&self, text: &str) -> String;
 
-    fn loot_info(&self) -> Spans;
+    fn combat_info(&self, theme: Theme, language: Language) -> Spans<'static>;
 
-    fn flavor_text(&self) -> Spans;
+    fn loot_info(&self, theme: Theme, language: Language) -> Spans<'static>;
+
+    fn flavor_text(&self, language: Language) -> Spans<'static>;
+}
+
+/// Catalog text for `key`, with the dice it links to drawn in their style
+pub fn describe(
+    language: Language,
+    theme: Theme,
+    key: &str,
+    args: &[(&str, String)],
+) -> Spans<'static> {
+    language.spans(key, args, |die| {
+        let ally = (0..Ally::faces())
+            .map(Ally::nth)
+            .find(|a| a.id() == die)
+            .map(|a| a.style(theme));
+        let monster = || {
+            (0..Monster::faces())
+                .map(Monster::nth)
+                .find(|m| m.id() == die)
+                .map(|m| m.style(theme))
+        };
+        ally.or_else(monster).unwrap_or_default()
+    })
 }
 
-#[derive(Debug, Dice, PartialEq)]
+#[derive(Debug, Dice, PartialEq, Serialize, Deserialize)]
 pub enum Ally {
     Fighter,
     Cleric,
@@ -41,163 +117,62 @@ pub enum Ally {
 }
 
 impl Render for Ally {
-    fn style(&self) -> Style {
-        match self {
-            Ally::Fighter => Style::default().fg(Color::Green),
-            Ally::Cleric => Style::default().fg(Color::DarkGray),
-            Ally::Mage => Style::default().fg(Color::Blue),
-            Ally::Thief => Style::default().fg(Color::Rgb(128, 0, 128)),
-            Ally::Champion => Style::default().fg(Color::Yellow),
-            Ally::Scroll => Style::default().fg(Color::Rgb(255, 165, 0)),
-        }
+    fn style(&self, theme: Theme) -> Style {
+        theme.ally(self)
     }
 
     fn symbol(&self) -> &'static str {
         match self {
             Ally::Fighter => "F",
-            Ally::Cleric | Ally::Champion => "C",
+            Ally::Cleric => "C",
             Ally::Mage => "M",
             Ally::Thief => "T",
-            Ally::Scroll => "S",
+            Ally::Champion => "H",
+            Ally::Scroll => "R",
         }
     }
 
-    fn combat_info(&self) -> Spans {
+    #[allow(clippy::non_ascii_literal)]
+    fn icon(&self) -> &'static str {
         match self {
-            Ally::Fighter => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Fighter", self.style()),
-                Span::raw(" defeats one "),
-                Span::styled("Skeleton", Monster::Skeleton.style()),
-                Span::raw(", one "),
-                Span::styled("Ooze", Monster::Ooze.style()),
-                Span::raw(", or any number of "),
-                Span::styled("Goblins", Monster::Goblin.style()),
-            ]),
-            Ally::Cleric => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Cleric", self.style()),
-                Span::raw(" defeats one "),
-                Span::styled("Goblin", Monster::Goblin.style()),
-                Span::raw(", one "),
-                Span::styled("Ooze", Monster::Ooze.style()),
-                Span::raw(", or any number of "),
-                Span::styled("Skeletons", Monster::Skeleton.style()),
-            ]),
-            Ally::Mage => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Mage", self.style()),
-                Span::raw(" defeats one "),
-                Span::styled("Goblin", Monster::Goblin.style()),
-                Span::raw(", one "),
-                Span::styled("Skeleton", Monster::Skeleton.style()),
-                Span::raw(", or any number of "),
-                Span::styled("Oozes", Monster::Ooze.style()),
-            ]),
-            Ally::Thief => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Thief", self.style()),
-                Span::raw(" defeats one "),
-                Span::styled("Goblin", Monster::Goblin.style()),
-                Span::raw(", one "),
-                Span::styled("Skeleton", Monster::Skeleton.style()),
-                Span::raw(", or one "),
-                Span::styled("Oozes", Monster::Ooze.style()),
-            ]),
-            Ally::Champion => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Champion", self.style()),
-                Span::raw(" may be used to defeat any number of "),
-                Span::styled("Goblins", Monster::Goblin.style()),
-                Span::raw(", any number of "),
-                Span::styled("Skeletons", Monster::Skeleton.style()),
-                Span::raw(", or any number of "),
-                Span::styled("Oozes", Monster::Ooze.style()),
-            ]),
-            Ally::Scroll => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Scroll", self.style()),
-                Span::raw(" may be used to re-roll any number of Dungeon and Party dice except for Dragon faces"),
-            ]),
+            Ally::Fighter => "⚔",
+            Ally::Cleric => "✚",
+            Ally::Mage => "✶",
+            Ally::Thief => "✂",
+            Ally::Champion => "♛",
+            Ally::Scroll => "§",
         }
     }
 
-    fn loot_info(&self) -> Spans {
-        match self {
-            Ally::Fighter => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Fighter", self.style()),
-                Span::raw(" may be used to open one "),
-                Span::styled("Chests", Monster::Chest.style()),
-                Span::raw(" or quaff any number of "),
-                Span::styled("Potions", Monster::Potion.style()),
-            ]),
-            Ally::Cleric => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Cleric", self.style()),
-                Span::raw(" may be used to open one "),
-                Span::styled("Chests", Monster::Chest.style()),
-                Span::raw(" or quaff any number of "),
-                Span::styled("Potions", Monster::Potion.style()),
-            ]),
-            Ally::Mage => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Mage", self.style()),
-                Span::raw(" may be used to open one "),
-                Span::styled("Chests", Monster::Chest.style()),
-                Span::raw(" or quaff any number of "),
-                Span::styled("Potions", Monster::Potion.style()),
-            ]),
-            Ally::Thief => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Thief", self.style()),
-                Span::raw(" may be used to open any number of "),
-                Span::styled("Chests", Monster::Chest.style()),
-                Span::raw(" or quaff any number of "),
-                Span::styled("Potions", Monster::Potion.style()),
-            ]),
-            Ally::Champion => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Champion", self.style()),
-                Span::raw(" may be used to open one "),
-                Span::styled("Chests", Monster::Chest.style()),
-                Span::raw(" or quaff any number of "),
-                Span::styled("Potions", Monster::Potion.style()),
-            ]),
-            Ally::Scroll => Spans::from(vec![
-                Span::raw("The "),
-                Span::styled("Scroll", self.style()),
-                Span::raw(" may be used to quaff any number of "),
-                Span::styled("Potions", Monster::Potion.style()),
-            ]),
-        }
+    fn key(&self, text: &str) -> String {
+        format!("ally.{}.{text}", self.id())
     }
 
-    fn flavor_text(&self) -> Spans {
+    fn id(&self) -> &'static str {
         match self {
-            Ally::Fighter => Spans::from(Span::styled(
-                "Who send all these babies to fight?",
-                *FLAVOR_STYLE,
-            )),
-            Ally::Cleric => Spans::from(Span::styled("I kick ass for the Lord!", *FLAVOR_STYLE)),
-            Ally::Mage => Spans::from(Span::styled(
-                "Master of the arcane, relegated to disposing of goo",
-                *FLAVOR_STYLE,
-            )),
-            Ally::Thief => Spans::from(Span::styled(
-                "Thief is not the one who steals, but the one that is caught",
-                *FLAVOR_STYLE,
-            )),
-            Ally::Champion => Spans::from(Span::styled(
-                "Monster! You have no bearing, and no grace or courage!",
-                *FLAVOR_STYLE,
-            )),
-            Ally::Scroll => Spans::from(Span::styled(
-                "Never trust anyone who has not brought a scroll with them",
-                *FLAVOR_STYLE,
-            )),
+            Ally::Fighter => "fighter",
+            Ally::Cleric => "cleric",
+            Ally::Mage => "mage",
+            Ally::Thief => "thief",
+            Ally::Champion => "champion",
+            Ally::Scroll => "scroll",
         }
     }
+
+    fn combat_info(&self, theme: Theme, language: Language) -> Spans<'static> {
+        describe(language, theme, &self.key("combat"), &[])
+    }
+
+    fn loot_info(&self, theme: Theme, language: Language) -> Spans<'static> {
+        describe(language, theme, &self.key("loot"), &[])
+    }
+
+    fn flavor_text(&self, language: Language) -> Spans<'static> {
+        Spans::from(Span::styled(
+            language.text(&self.key("flavor")),
+            *FLAVOR_STYLE,
+        ))
+    }
 }
 
 impl Ally {
@@ -206,7 +181,7 @@ impl Ally {
     }
 }
 
-#[derive(Debug, Dice, PartialEq, Clone)]
+#[derive(Debug, Dice, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Monster {
     Goblin,
     Skeleton,
@@ -217,15 +192,8 @@ pub enum Monster {
 }
 
 impl Render for Monster {
-    fn style(&self) -> Style {
-        match self {
-            Monster::Goblin => Style::default().fg(Color::Green),
-            Monster::Skeleton => Style::default().fg(Color::DarkGray),
-            Monster::Ooze => Style::default().fg(Color::Blue),
-            Monster::Dragon => Style::default().fg(Color::Red),
-            Monster::Chest => Style::default().fg(Color::Rgb(128, 0, 128)),
-            Monster::Potion => Style::default().fg(Color::Rgb(255, 165, 0)),
-        }
+    fn style(&self, theme: Theme) -> Style {
+        theme.monster(self)
     }
 
     fn symbol(&self) -> &'static str {
@@ -239,31 +207,46 @@ impl Render for Monster {
         }
     }
 
-    fn combat_info(&self) -> Spans {
-        self.info()
+    #[allow(clippy::non_ascii_literal)]
+    fn icon(&self) -> &'static str {
+        match self {
+            Monster::Goblin => "☻",
+            Monster::Skeleton => "☠",
+            Monster::Ooze => "≈",
+            Monster::Dragon => "Ж",
+            Monster::Chest => "▣",
+            Monster::Potion => "⚗",
+        }
+    }
+
+    fn key(&self, text: &str) -> String {
+        format!("monster.{}.{text}", self.id())
+    }
+
+    fn id(&self) -> &'static str {
+        match self {
+            Monster::Goblin => "goblin",
+            Monster::Skeleton => "skeleton",
+            Monster::Ooze => "ooze",
+            Monster::Dragon => "dragon",
+            Monster::Chest => "chest",
+            Monster::Potion => "potion",
+        }
     }
 
-    fn loot_info(&self) -> Spans {
-        self.info()
+    fn combat_info(&self, theme: Theme, language: Language) -> Spans<'static> {
+        self.info(theme, language)
     }
 
-    fn flavor_text(&self) -> Spans {
+    fn loot_info(&self, theme: Theme, language: Language) -> Spans<'static> {
+        self.info(theme, language)
+    }
+
+    fn flavor_text(&self, language: Language) -> Spans<'static> {
+        let text = language.text(&self.key("flavor"));
         match self {
-            Monster::Goblin => Spans::from(Span::styled(
-                "Don't let them gang up on you!",
-                *FLAVOR_STYLE,
-            )),
-            Monster::Skeleton => Spans::from(Span::styled(
-                "They appear very strong, they must have drunk a lot of milk",
-                *FLAVOR_STYLE,
-            )),
-            Monster::Ooze => Spans::from(Span::styled(
-                "Gooey and sticky, very hard to get off your clothes",
-                *FLAVOR_STYLE,
-            )),
-            Monster::Dragon => Spans::from("If you can read this, you found a bug"),
-            Monster::Chest => Spans::from(Span::styled("Don't be a mimic, please", *FLAVOR_STYLE)),
-            Monster::Potion => Spans::from(Span::styled("This can't taste good", *FLAVOR_STYLE)),
+            Monster::Dragon => Spans::from(Span::raw(text)),
+            _ => Spans::from(Span::styled(text, *FLAVOR_STYLE)),
         }
     }
 }
@@ -277,31 +260,7 @@ impl Monster {
         matches!(self, Monster::Chest | Monster::Potion)
     }
 
-    fn info(&self) -> Spans {
-        match self {
-            Monster::Goblin => Spans::from(vec![
-                Span::styled("Goblins", self.style()),
-                Span::raw(" are small humanoids that dwell in shallow underground lairs"),
-            ]),
-            Monster::Skeleton => Spans::from(vec![
-                Span::styled("Skeletons", self.style()),
-                Span::raw(" are undead creatures reanimated by necromantic magic"),
-            ]),
-            Monster::Ooze => Spans::from(vec![
-                Span::styled("Oozes", self.style()),
-                Span::raw(
-                    " are creatures that resemble amorphous blobs and dwell in the underground",
-                ),
-            ]),
-            Monster::Dragon => Spans::from("If you can read this, you found a bug"),
-            Monster::Chest => Spans::from(vec![
-                Span::styled("Chests", self.style()),
-                Span::raw(" contain treasures that may aid you in your quest"),
-            ]),
-            Monster::Potion => Spans::from(vec![
-                Span::styled("Potions", self.style()),
-                Span::raw(" may be used to bring an ally back from the graveyard"),
-            ]),
-        }
+    fn info(&self, theme: Theme, language: Language) -> Spans<'static> {
+        describe(language, theme, &self.key("info"), &[])
     }
 }
@@ -0,0 +1,174 @@
+use crate::dice::Dice;
+use rand::{Rng, RngCore};
+use rand_pcg::Pcg64Mcg;
+use std::{
+    any::TypeId,
+    collections::{HashMap, VecDeque},
+};
+
+/// Where dice get their faces from, anything else the game draws (like
+/// treasure) comes from the underlying `Rng`
+pub trait DiceSource: Rng {
+    fn face<T: Dice + 'static>(&mut self) -> u64 {
+        self.gen_range(0..T::faces())
+    }
+}
+
+impl DiceSource for Pcg64Mcg {}
+
+/// Rolls queued faces first, per die type, and falls back to `rng` once a
+/// queue runs out
+///
+/// ```ignore
+/// let mut dice = ScriptedDice::new(Pcg64Mcg::seed_from_u64(0));
+/// dice.queue([Monster::Dragon, Monster::Dragon, Monster::Dragon]);
+/// ```
+pub struct ScriptedDice<R: Rng = Pcg64Mcg> {
+    rng: R,
+    queues: HashMap<TypeId, VecDeque<u64>>,
+}
+
+impl<R: DiceSource> ScriptedDice<R> {
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            queues: HashMap::new(),
+        }
+    }
+
+    /// Makes the next dice of type `T` roll `faces`, in order
+    pub fn queue<T: Dice + 'static, I: IntoIterator<Item = T>>(&mut self, faces: I) {
+        self.queues
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .extend(faces.into_iter().map(|f| f.face()));
+    }
+
+    /// Number of faces of type `T` not rolled yet
+    pub fn pending<T: Dice + 'static>(&self) -> usize {
+        self.queues.get(&TypeId::of::<T>()).map_or(0, VecDeque::len)
+    }
+}
+
+impl<R: DiceSource> RngCore for ScriptedDice<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl<R: DiceSource> DiceSource for ScriptedDice<R> {
+    fn face<T: Dice + 'static>(&mut self) -> u64 {
+        match self
+            .queues
+            .get_mut(&TypeId::of::<T>())
+            .and_then(VecDeque::pop_front)
+        {
+            Some(face) => face,
+            None => self.rng.gen_range(0..T::faces()),
+        }
+    }
+}
+
+/// Keeps every number `rng` draws, so that another game can play the same
+/// rolls with `Replay` without ever learning the seed
+pub struct Recorder<R: RngCore = Pcg64Mcg> {
+    rng: R,
+    drawn: Vec<u64>,
+}
+
+impl<R: RngCore> Recorder<R> {
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            drawn: Vec::new(),
+        }
+    }
+
+    /// The numbers drawn since the last call
+    pub fn take(&mut self) -> Vec<u64> {
+        std::mem::take(&mut self.drawn)
+    }
+}
+
+impl<R: RngCore> RngCore for Recorder<R> {
+    fn next_u32(&mut self) -> u32 {
+        let n = self.rng.next_u32();
+        self.drawn.push(u64::from(n));
+        n
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let n = self.rng.next_u64();
+        self.drawn.push(n);
+        n
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<R: RngCore> DiceSource for Recorder<R> {}
+
+/// Draws the numbers a `Recorder` kept, in the same order
+#[derive(Debug, Default)]
+pub struct Replay {
+    drawn: VecDeque<u64>,
+}
+
+impl Replay {
+    /// Makes the next draws return `drawn`, after anything pushed before
+    pub fn push(&mut self, drawn: impl IntoIterator<Item = u64>) {
+        self.drawn.extend(drawn);
+    }
+}
+
+impl RngCore for Replay {
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u32(&mut self) -> u32 {
+        // recorded from a `next_u32`, so it fits
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.drawn
+            .pop_front()
+            .expect("a game replays the same draws it recorded")
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl DiceSource for Replay {}
+
+/// Fills `dest` from `next_u64`, so that it's recorded and replayed the same
+fn fill_bytes(rng: &mut impl RngCore, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+        let bytes = rng.next_u64().to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    dice::{Ally, Dice, Monster},
+    game::{Command, Game},
+    phase::{Dragon, Loot, Monster as MonsterPhase, Phase},
+    roster::Roster,
+    rules::Rules,
+    treasure::Treasure,
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+use std::thread;
+
+#[allow(clippy::cast_possible_truncation)]
+const ALLY_FACES: usize = Ally::FACES as usize;
+#[allow(clippy::cast_possible_truncation)]
+const MONSTER_FACES: usize = Monster::FACES as usize;
+const TREASURES: usize = Treasure::ALL.len();
+const PHASES: usize = 5;
+
+/// Fewest environments given a thread of their own, smaller batches are
+/// stepped faster than the threads are spawned
+const ENVS_PER_THREAD: usize = 64;
+
+/// Face counts of the party, dungeon and graveyard, item counts of the
+/// inventory, then delve, level and a one-hot of the decision phase
+pub const OBSERVATION_SIZE: usize = 2 * ALLY_FACES + MONSTER_FACES + TREASURES + 2 + PHASES;
+
+/// Reward for a command the game refuses, the state is left untouched
+pub const ILLEGAL_REWARD: f32 = -1.;
+
+pub type Observation = [f32; OBSERVATION_SIZE];
+
+fn new_game(rules: &Rules, seed: u64) -> Game<Pcg64Mcg> {
+    let mut game = Game::new(
+        Pcg64Mcg::seed_from_u64(seed),
+        Roster::default(),
+        rules.clone(),
+    );
+    game.perform(&Command::Start)
+        .expect("a new game can always be started");
+    game
+}
+
+/// A single-hero game played one delve per episode, rewarded with the XP the
+/// hero banks
+pub struct Env {
+    rules: Rules,
+    game: Game<Pcg64Mcg>,
+    done: bool,
+}
+
+impl Env {
+    pub fn new(rules: Rules) -> Self {
+        Self {
+            game: new_game(&rules, 0),
+            rules,
+            done: false,
+        }
+    }
+
+    /// Starts a new delve whose dice only depend on `seed`
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        self.game = new_game(&self.rules, seed);
+        self.done = false;
+        self.observation()
+    }
+
+    /// Commands accepted by `step`, see `Game::legal_commands`
+    pub fn legal_actions(&self) -> Vec<Command> {
+        if self.done {
+            Vec::new()
+        } else {
+            self.game.legal_commands()
+        }
+    }
+
+    pub fn step(&mut self, action: &Command) -> (Observation, f32, bool) {
+        self.try_step(action)
+            .unwrap_or_else(|_| (self.observation(), ILLEGAL_REWARD, false))
+    }
+
+    /// Like `step`, but reports why an illegal command was refused
+    pub fn try_step(&mut self, action: &Command) -> Result<(Observation, f32, bool), String> {
+        if self.done {
+            return Ok((self.observation(), 0., true));
+        }
+
+        let (xp, delves) = self.progress();
+        self.game.perform(action)?;
+
+        let (new_xp, new_delves) = self.progress();
+        self.done =
+            new_delves > delves || matches!(self.game.phase(), Phase::Victory | Phase::GameOver);
+
+        #[allow(clippy::cast_precision_loss)]
+        Ok((self.observation(), (new_xp - xp) as f32, self.done))
+    }
+
+    pub fn game(&self) -> &Game<Pcg64Mcg> {
+        &self.game
+    }
+
+    fn progress(&self) -> (u64, u64) {
+        let observation = self.game.observe();
+        let hero = &observation.roster[observation.players[observation.player]];
+        (hero.xp(), hero.delves())
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn observation(&self) -> Observation {
+        let observation = self.game.observe();
+        let mut encoded = [0.; OBSERVATION_SIZE];
+
+        let (party, rest) = encoded.split_at_mut(ALLY_FACES);
+        let (dungeon, rest) = rest.split_at_mut(MONSTER_FACES);
+        let (graveyard, rest) = rest.split_at_mut(ALLY_FACES);
+        let (inventory, rest) = rest.split_at_mut(TREASURES);
+        let (depth, phase) = rest.split_at_mut(2);
+
+        for ally in observation.party {
+            party[ally.face() as usize] += 1.;
+        }
+        for monster in observation.dungeon {
+            dungeon[monster.face() as usize] += 1.;
+        }
+        for ally in observation.graveyard {
+            graveyard[ally.face() as usize] += 1.;
+        }
+        for item in observation.inventory {
+            inventory[item.clone() as usize] += 1.;
+        }
+        depth[0] = observation.delve as f32;
+        depth[1] = observation.level as f32;
+
+        let decision = match self.game.phase() {
+            Phase::Monster(MonsterPhase::SelectAlly) => Some(0),
+            Phase::Loot(Loot::SelectAlly) => Some(1),
+            Phase::Dragon(Dragon::SelectAlly) => Some(2),
+            Phase::Regroup(_) => Some(3),
+            Phase::EmptyDungeon => Some(4),
+            _ => None,
+        };
+        if let Some(i) = decision {
+            phase[i] = 1.;
+        }
+
+        encoded
+    }
+}
+
+/// Several environments stepped side by side, on all available cores once
+/// there are enough of them
+pub struct BatchEnv {
+    envs: Vec<Env>,
+}
+
+impl BatchEnv {
+    pub fn new(rules: &Rules, size: usize) -> Self {
+        Self {
+            envs: (0..size).map(|_| Env::new(rules.clone())).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.envs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.envs.is_empty()
+    }
+
+    pub fn envs(&self) -> &[Env] {
+        &self.envs
+    }
+
+    /// # Panics
+    ///
+    /// If there isn't exactly one seed per environment
+    pub fn reset(&mut self, seeds: &[u64]) -> Vec<Observation> {
+        assert_eq!(seeds.len(), self.envs.len(), "one seed per environment");
+        self.envs
+            .iter_mut()
+            .zip(seeds)
+            .map(|(env, seed)| env.reset(*seed))
+            .collect()
+    }
+
+    /// Steps every environment with its own action, environments that are
+    /// done stay done until they are reset
+    ///
+    /// # Panics
+    ///
+    /// If there isn't exactly one action per environment
+    pub fn step(&mut self, actions: &[Command]) -> Vec<(Observation, f32, bool)> {
+        assert_eq!(actions.len(), self.envs.len(), "one action per environment");
+        let threads = thread::available_parallelism().map_or(1, usize::from);
+        let chunk = self.envs.len().div_ceil(threads).max(ENVS_PER_THREAD);
+        if chunk >= self.envs.len() {
+            return self
+                .envs
+                .iter_mut()
+                .zip(actions)
+                .map(|(env, action)| env.step(action))
+                .collect();
+        }
+
+        thread::scope(|s| {
+            self.envs
+                .chunks_mut(chunk)
+                .zip(actions.chunks(chunk))
+                .map(|(envs, actions)| {
+                    s.spawn(move || {
+                        envs.iter_mut()
+                            .zip(actions)
+                            .map(|(env, action)| env.step(action))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+}
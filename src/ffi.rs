@@ -0,0 +1,178 @@
+//! C bindings for the rules engine, see `include/dungeon_roll.h`. Every entry
+//! point catches panics and reports failures through its return value.
+
+use crate::{
+    env::{Env, OBSERVATION_SIZE},
+    game::Command,
+    rules::Rules,
+};
+use serde_json::json;
+use std::{
+    ffi::{c_char, CStr, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr, slice,
+};
+
+pub const DR_OK: i32 = 0;
+pub const DR_NULL: i32 = -1;
+pub const DR_INVALID_UTF8: i32 = -2;
+pub const DR_MALFORMED: i32 = -3;
+pub const DR_ILLEGAL: i32 = -4;
+pub const DR_BUFFER_TOO_SMALL: i32 = -5;
+pub const DR_PANIC: i32 = -6;
+
+fn guard<F: FnOnce() -> i32>(f: F) -> i32 {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(DR_PANIC)
+}
+
+/// Creates a game whose dice only depend on `seed`, returns null on failure
+#[no_mangle]
+pub extern "C" fn dr_game_new(seed: u64) -> *mut Env {
+    catch_unwind(|| {
+        let mut env = Env::new(Rules::default());
+        env.reset(seed);
+        Box::into_raw(Box::new(env))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Frees a game created by `dr_game_new`
+///
+/// # Safety
+///
+/// `game` must be null or a pointer returned by `dr_game_new` that wasn't
+/// freed yet
+#[no_mangle]
+pub unsafe extern "C" fn dr_game_free(game: *mut Env) {
+    if !game.is_null() {
+        let _ = catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(game))));
+    }
+}
+
+/// Starts a new delve with `seed`
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `dr_game_new`
+#[no_mangle]
+pub unsafe extern "C" fn dr_game_reset(game: *mut Env, seed: u64) -> i32 {
+    let Some(game) = game.as_mut() else {
+        return DR_NULL;
+    };
+
+    guard(|| {
+        game.reset(seed);
+        DR_OK
+    })
+}
+
+/// Plays `command`, a JSON command object as accepted by the `--json` mode,
+/// writing the reward and whether the delve ended to `reward` and `done`
+/// when they aren't null
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `dr_game_new`,
+/// `command` must be null or a nul-terminated string, `reward` and `done`
+/// must be null or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn dr_game_step(
+    game: *mut Env,
+    command: *const c_char,
+    reward: *mut f32,
+    done: *mut bool,
+) -> i32 {
+    let Some(game) = game.as_mut() else {
+        return DR_NULL;
+    };
+    if command.is_null() {
+        return DR_NULL;
+    }
+    let Ok(command) = CStr::from_ptr(command).to_str() else {
+        return DR_INVALID_UTF8;
+    };
+
+    guard(|| {
+        let Ok(command) = serde_json::from_str::<Command>(command) else {
+            return DR_MALFORMED;
+        };
+        match game.try_step(&command) {
+            Ok((_, r, d)) => {
+                if !reward.is_null() {
+                    *reward = r;
+                }
+                if !done.is_null() {
+                    *done = d;
+                }
+                DR_OK
+            }
+            Err(_) => DR_ILLEGAL,
+        }
+    })
+}
+
+/// Writes the fixed-size observation encoding to `out`, which must hold at
+/// least `dr_observation_size()` floats
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `dr_game_new`, `out`
+/// must be null or valid for `len` writes
+#[no_mangle]
+pub unsafe extern "C" fn dr_game_observation(game: *const Env, out: *mut f32, len: usize) -> i32 {
+    let Some(game) = game.as_ref() else {
+        return DR_NULL;
+    };
+    if out.is_null() {
+        return DR_NULL;
+    }
+    if len < OBSERVATION_SIZE {
+        return DR_BUFFER_TOO_SMALL;
+    }
+    let out = slice::from_raw_parts_mut(out, OBSERVATION_SIZE);
+
+    guard(|| {
+        out.copy_from_slice(&game.observation());
+        DR_OK
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn dr_observation_size() -> usize {
+    OBSERVATION_SIZE
+}
+
+/// Returns the observable state and legal commands as a JSON object, to be
+/// freed with `dr_string_free`, or null on failure
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `dr_game_new`
+#[no_mangle]
+pub unsafe extern "C" fn dr_game_state(game: *const Env) -> *mut c_char {
+    let Some(game) = game.as_ref() else {
+        return ptr::null_mut();
+    };
+
+    catch_unwind(AssertUnwindSafe(|| {
+        let state = json!({
+            "observation": game.game().observe(),
+            "legal": game.legal_actions(),
+        });
+        CString::new(state.to_string()).map_or(ptr::null_mut(), CString::into_raw)
+    }))
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Frees a string returned by `dr_game_state`
+///
+/// # Safety
+///
+/// `s` must be null or a pointer returned by `dr_game_state` that wasn't
+/// freed yet
+#[no_mangle]
+pub unsafe extern "C" fn dr_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        let _ = catch_unwind(AssertUnwindSafe(|| drop(CString::from_raw(s))));
+    }
+}
@@ -0,0 +1,51 @@
+use super::{DiceSource, Game};
+use crate::achievements::{Achievement, Achievements};
+
+/// How many blinks a toast stays on screen
+const TOAST_TICKS: u8 = 6;
+
+impl<R: DiceSource> Game<R> {
+    /// Unlocks achievements into `achievements` from now on
+    pub fn track_achievements(&mut self, achievements: Achievements) {
+        self.achievements = Some(achievements);
+    }
+
+    /// Returns the achievements if one was unlocked since the last call
+    pub fn take_achievements(&mut self) -> Option<&Achievements> {
+        if self.achievements_changed {
+            self.achievements_changed = false;
+            self.achievements.as_ref()
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn achieve(&mut self, achievement: Achievement) {
+        if let Some(achievements) = &mut self.achievements {
+            if achievements.unlock(achievement) {
+                self.achievements_changed = true;
+                if self.toasts.is_empty() {
+                    self.toast_ticks = TOAST_TICKS;
+                }
+                self.toasts.push_back(achievement);
+            }
+        }
+    }
+
+    /// The achievement currently being announced
+    pub fn toast(&self) -> Option<Achievement> {
+        self.toasts.front().copied()
+    }
+
+    pub(super) fn tick_toasts(&mut self) {
+        if self.toasts.is_empty() {
+            return;
+        }
+
+        self.toast_ticks = self.toast_ticks.saturating_sub(1);
+        if self.toast_ticks == 0 {
+            self.toasts.pop_front();
+            self.toast_ticks = TOAST_TICKS;
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Dice, DiceSource, Game, Row};
+use std::time::Duration;
+
+/// Frames a roll lasts
+pub const ROLL_FRAMES: u8 = 10;
+const ROLL_TICK: Duration = Duration::from_millis(60);
+const BLINK_TICK: Duration = Duration::from_millis(500);
+
+/// Dice that were just rolled and still show random faces
+pub(super) struct Roll {
+    party: Vec<usize>,
+    dungeon: Vec<usize>,
+    frames: u8,
+}
+
+impl<R: DiceSource> Game<R> {
+    pub fn set_animations(&mut self, animations: bool) {
+        self.animations = animations;
+    }
+
+    pub fn is_rolling(&self) -> bool {
+        self.roll.is_some()
+    }
+
+    /// How long to wait before the next `tick`
+    pub fn tick_rate(&self) -> Duration {
+        if self.is_rolling() {
+            ROLL_TICK
+        } else {
+            BLINK_TICK
+        }
+    }
+
+    /// Advances the roll, or blinks the cursor once the dice have settled
+    pub fn tick(&mut self) {
+        if self.is_rolling() {
+            self.tick_roll();
+        } else {
+            self.toggle_blink();
+        }
+    }
+
+    /// Advances the roll, settling the dice on their faces once it's over
+    fn tick_roll(&mut self) {
+        if let Some(roll) = &mut self.roll {
+            roll.frames = roll.frames.saturating_sub(1);
+            if roll.frames == 0 {
+                self.roll = None;
+            }
+        }
+    }
+
+    pub fn skip_roll(&mut self) {
+        self.roll = None;
+    }
+
+    /// Starts rolling the dice at the given indices, along with any that are
+    /// still rolling
+    pub(super) fn animate_roll(&mut self, party: &[usize], dungeon: &[usize]) {
+        if !self.animations {
+            return;
+        }
+
+        let roll = self.roll.get_or_insert(Roll {
+            party: Vec::new(),
+            dungeon: Vec::new(),
+            frames: 0,
+        });
+        roll.party.extend(party);
+        roll.dungeon.extend(dungeon);
+        roll.frames = ROLL_FRAMES;
+    }
+
+    /// The face die `i` of `row` shows while it's rolling
+    pub(super) fn rolling_face<T: Dice>(&self, row: Row, i: usize) -> Option<T> {
+        let roll = self.roll.as_ref()?;
+        let rolling = match row {
+            Row::Party => &roll.party,
+            Row::Dungeon => &roll.dungeon,
+            Row::Graveyard => return None,
+        };
+
+        // neighbouring dice are kept on different faces
+        rolling
+            .contains(&i)
+            .then(|| T::nth((u64::from(roll.frames) + 2 * i as u64) % T::faces()))
+    }
+}
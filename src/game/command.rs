@@ -0,0 +1,363 @@
+use super::{
+    Ally, DiceSource, DragonPhase, DungeonCursor, Game, LootPhase, Mode, Monster, MonsterPhase,
+    PartyCursor, Phase, RegroupPhase, Treasure, MAX_PLAYERS,
+};
+use crate::hero::Hero;
+use serde::{Deserialize, Serialize};
+
+/// A whole move, as opposed to the cursor movements of `Action`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "lowercase")]
+pub enum Command {
+    Hero {
+        index: usize,
+    },
+    Player,
+    Mode {
+        mode: Mode,
+    },
+    Start,
+    Fight {
+        ally: usize,
+        monster: usize,
+    },
+    Reroll {
+        #[serde(default)]
+        party: Vec<usize>,
+        #[serde(default)]
+        dungeon: Vec<usize>,
+    },
+    Open {
+        ally: usize,
+        chest: usize,
+    },
+    Quaff {
+        ally: usize,
+        #[serde(default)]
+        graves: Option<Vec<usize>>,
+    },
+    Skip,
+    Dragon {
+        allies: Vec<usize>,
+    },
+    Continue,
+    Retire,
+    Next,
+}
+
+/// Everything a player can see
+#[derive(Debug, Serialize)]
+pub struct Observation<'a> {
+    pub phase: String,
+    pub roster: &'a [Hero],
+    pub selected_hero: usize,
+    pub players: Vec<usize>,
+    pub player: usize,
+    pub delve: u64,
+    pub level: u64,
+    pub run_xp: u64,
+    pub dungeon: &'a [Monster],
+    pub party: &'a [Ally],
+    pub graveyard: &'a [Ally],
+    pub inventory: &'a [Treasure],
+}
+
+impl<R: DiceSource> Game<R> {
+    pub fn observe(&self) -> Observation<'_> {
+        Observation {
+            phase: format!("{:?}", self.phase),
+            roster: &self.roster,
+            selected_hero: self.selected_hero,
+            players: self.players.iter().map(|p| p.hero).collect(),
+            player: self.player,
+            delve: self.delve,
+            level: self.level,
+            run_xp: self.run_xp,
+            dungeon: &self.dungeon,
+            party: &self.party,
+            graveyard: &self.graveyard,
+            inventory: if self.players.is_empty() {
+                &[]
+            } else {
+                self.inventory()
+            },
+        }
+    }
+
+    /// Lists the commands accepted in the current phase. The lists of
+    /// `Reroll`, `Quaff` and `Dragon` hold every die that may be picked, any
+    /// subset of them is legal too (exactly three for the dragon).
+    pub fn legal_commands(&self) -> Vec<Command> {
+        let allies = |f: fn(&Ally) -> bool| {
+            (0..self.party.len())
+                .filter(|i| f(&self.party[*i]))
+                .collect::<Vec<_>>()
+        };
+        let dice = |f: fn(&Monster) -> bool| {
+            (0..self.dungeon.len())
+                .filter(|i| f(&self.dungeon[*i]))
+                .collect::<Vec<_>>()
+        };
+
+        let mut commands = Vec::new();
+        match self.phase {
+            Phase::Start => {
+                commands.extend(
+                    (0..self.roster.len())
+                        .filter(|i| *i != self.selected_hero)
+                        .map(|index| Command::Hero { index }),
+                );
+                if self.players.len() < MAX_PLAYERS
+                    || self.players.iter().any(|p| p.hero == self.selected_hero)
+                {
+                    commands.push(Command::Player);
+                }
+                if self.setup_hero().delves() == 0 {
+                    commands.push(Command::Mode {
+                        mode: match self.setup_hero().mode() {
+                            Mode::Campaign => Mode::Classic,
+                            Mode::Classic => Mode::Campaign,
+                        },
+                    });
+                }
+                commands.push(Command::Start);
+            }
+            Phase::Monster(MonsterPhase::SelectAlly) => {
+                for ally in allies(Ally::is_companion) {
+                    for monster in dice(Monster::is_monster) {
+                        commands.push(Command::Fight { ally, monster });
+                    }
+                }
+                if let Some(scroll) = self.party.iter().position(|a| a == &Ally::Scroll) {
+                    commands.push(Command::Reroll {
+                        party: (0..self.party.len()).filter(|i| *i != scroll).collect(),
+                        dungeon: dice(|m| m != &Monster::Dragon),
+                    });
+                }
+            }
+            Phase::Loot(LootPhase::SelectAlly) => {
+                for ally in allies(Ally::is_companion) {
+                    for chest in dice(|m| m == &Monster::Chest) {
+                        commands.push(Command::Open { ally, chest });
+                    }
+                }
+                if self.potion_count() > 0 && !self.graveyard.is_empty() {
+                    commands.extend((0..self.party.len()).map(|ally| Command::Quaff {
+                        ally,
+                        graves: Some((0..self.graveyard.len()).collect()),
+                    }));
+                }
+                commands.push(Command::Skip);
+            }
+            Phase::Dragon(DragonPhase::SelectAlly) => commands.push(Command::Dragon {
+                allies: allies(Ally::is_companion),
+            }),
+            Phase::Regroup(_) => commands.extend([Command::Continue, Command::Retire]),
+            Phase::Scoreboard
+            | Phase::Victory
+            | Phase::EmptyDungeon
+            | Phase::Monster(MonsterPhase::Defeat)
+            | Phase::Dragon(DragonPhase::Defeat) => {
+                commands.push(Command::Next);
+            }
+            _ => (),
+        }
+
+        commands
+    }
+
+    /// Plays a whole move, leaving the game untouched if it isn't legal
+    pub fn perform(&mut self, command: &Command) -> Result<(), String> {
+        match (command, &self.phase) {
+            (Command::Hero { index }, Phase::Start) => {
+                if *index >= self.roster.len() {
+                    return Err(format!("there is no hero {index}"));
+                }
+                self.selected_hero = *index;
+            }
+            (Command::Player, Phase::Start) => self.toggle_player(),
+            (Command::Mode { mode }, Phase::Start) => self.select_mode(*mode),
+            (Command::Start, Phase::Start) => self.start(),
+            (Command::Fight { ally, monster }, _) => self.perform_fight(*ally, *monster)?,
+            (Command::Reroll { party, dungeon }, _) => self.perform_reroll(party, dungeon)?,
+            (Command::Open { ally, chest }, _) => self.perform_open(*ally, *chest)?,
+            (Command::Quaff { ally, graves }, _) => self.perform_quaff(*ally, graves.clone())?,
+            (Command::Skip, Phase::Loot(LootPhase::SelectAlly)) => self.prev_phase(),
+            (Command::Dragon { allies }, _) => self.perform_dragon(allies)?,
+            (Command::Continue, Phase::Regroup(_)) => {
+                self.phase = Phase::Regroup(RegroupPhase::Continue);
+                self.next_phase();
+            }
+            (Command::Retire, Phase::Regroup(_)) => {
+                self.phase = Phase::Regroup(RegroupPhase::End);
+                self.next_phase();
+            }
+            (Command::Next, Phase::Scoreboard | Phase::Victory) => self.next_turn(),
+            (Command::Next, Phase::EmptyDungeon) => self.next_phase(),
+            (
+                Command::Next,
+                Phase::Monster(MonsterPhase::Defeat) | Phase::Dragon(DragonPhase::Defeat),
+            ) => {
+                self.after_delve();
+            }
+            (command, phase) => return Err(format!("{command:?} can't be used during {phase:?}")),
+        }
+
+        self.blink = true;
+        Ok(())
+    }
+
+    fn expect_phase(&self, phase: &Phase) -> Result<(), String> {
+        if &self.phase == phase {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected {phase:?} but the game is in {:?}",
+                self.phase
+            ))
+        }
+    }
+
+    fn pick_ally(&mut self, ally: usize) -> Result<(), String> {
+        if self.party.set_cursor(PartyCursor::Ally as usize, ally) {
+            Ok(())
+        } else {
+            Err(format!("there is no ally {ally}"))
+        }
+    }
+
+    fn perform_fight(&mut self, ally: usize, monster: usize) -> Result<(), String> {
+        self.expect_phase(&Phase::Monster(MonsterPhase::SelectAlly))?;
+        if self.party.get(ally) == Some(&Ally::Scroll) {
+            return Err("scrolls can't fight, use reroll".to_string());
+        }
+        if !self.dungeon.get(monster).is_some_and(Monster::is_monster) {
+            return Err(format!("there is no monster {monster}"));
+        }
+
+        self.pick_ally(ally)?;
+        self.next_phase();
+        self.dungeon
+            .set_cursor(DungeonCursor::Monster as usize, monster);
+        self.next_phase();
+        self.next_phase();
+
+        Ok(())
+    }
+
+    fn perform_reroll(&mut self, party: &[usize], dungeon: &[usize]) -> Result<(), String> {
+        self.expect_phase(&Phase::Monster(MonsterPhase::SelectAlly))?;
+        let scroll = (0..self.party.len())
+            .find(|i| self.party[*i] == Ally::Scroll && !party.contains(i))
+            .ok_or("there is no scroll to use")?;
+        if let Some(i) = party.iter().find(|i| **i >= self.party.len()) {
+            return Err(format!("there is no ally {i}"));
+        }
+        if let Some(i) = dungeon
+            .iter()
+            .find(|i| self.dungeon.get(**i).is_none_or(|m| m == &Monster::Dragon))
+        {
+            return Err(format!("dungeon die {i} can't be re-rolled"));
+        }
+
+        self.pick_ally(scroll)?;
+        self.next_phase();
+        for i in party {
+            self.party.set_cursor(PartyCursor::Reroll as usize, *i);
+            if !self.party.is_selected(*i) {
+                self.party.toggle_select(PartyCursor::Reroll as usize);
+            }
+        }
+        for i in dungeon {
+            self.dungeon.set_cursor(DungeonCursor::Reroll as usize, *i);
+            if !self.dungeon.is_selected(*i) {
+                self.dungeon.toggle_select(DungeonCursor::Reroll as usize);
+            }
+        }
+        self.next_phase();
+        self.next_phase();
+
+        Ok(())
+    }
+
+    fn perform_open(&mut self, ally: usize, chest: usize) -> Result<(), String> {
+        self.expect_phase(&Phase::Loot(LootPhase::SelectAlly))?;
+        if self.party.get(ally) == Some(&Ally::Scroll) {
+            return Err("scrolls can't open chests".to_string());
+        }
+        if self.dungeon.get(chest) != Some(&Monster::Chest) {
+            return Err(format!("there is no chest {chest}"));
+        }
+
+        self.pick_ally(ally)?;
+        self.next_phase();
+        self.dungeon
+            .set_cursor(DungeonCursor::Monster as usize, chest);
+        self.next_phase();
+        self.next_phase();
+
+        Ok(())
+    }
+
+    fn perform_quaff(&mut self, ally: usize, graves: Option<Vec<usize>>) -> Result<(), String> {
+        self.expect_phase(&Phase::Loot(LootPhase::SelectAlly))?;
+        let potion = self
+            .dungeon
+            .iter()
+            .position(|m| m == &Monster::Potion)
+            .ok_or("there are no potions")?;
+        let graves =
+            graves.unwrap_or_else(|| (0..self.graveyard.len().min(self.potion_count())).collect());
+        if graves.is_empty() {
+            return Err("there is nobody to revive".to_string());
+        }
+        if graves.len() > self.potion_count() {
+            return Err(format!("there are only {} potions", self.potion_count()));
+        }
+        if let Some(i) = graves.iter().find(|i| **i >= self.graveyard.len()) {
+            return Err(format!("there is no grave {i}"));
+        }
+
+        self.pick_ally(ally)?;
+        self.next_phase();
+        self.dungeon
+            .set_cursor(DungeonCursor::Monster as usize, potion);
+        self.next_phase();
+        for i in &graves {
+            self.graveyard.set_cursor(0, *i);
+            if !self.graveyard.is_selected(*i) {
+                self.graveyard.toggle_select(0);
+            }
+        }
+        self.next_phase();
+        self.next_phase();
+
+        Ok(())
+    }
+
+    fn perform_dragon(&mut self, allies: &[usize]) -> Result<(), String> {
+        self.expect_phase(&Phase::Dragon(DragonPhase::SelectAlly))?;
+        let mut distinct = allies.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() != 3 {
+            return Err("the dragon needs three companions".to_string());
+        }
+        if !allies
+            .iter()
+            .all(|i| self.party.get(*i).is_some_and(Ally::is_companion))
+        {
+            return Err("the dragon can only be fought by companions".to_string());
+        }
+
+        self.party.clear_selection();
+        for i in &distinct {
+            self.party.set_cursor(PartyCursor::Ally as usize, *i);
+            self.party.toggle_select(PartyCursor::Ally as usize);
+        }
+        self.next_phase();
+        self.next_phase();
+
+        Ok(())
+    }
+}
@@ -1,42 +1,194 @@
 use super::{
-    DragonPhase, DungeonCursor, Game, LootPhase, MonsterPhase, PartyCursor, Phase, RegroupPhase,
-    Reroll, Rng,
+    utils::Cursor, DiceSource, DragonPhase, DungeonCursor, Game, Language, LootPhase, Mode,
+    MonsterPhase, PartyCursor, Phase, Player, RegroupPhase, Reroll, Row, Theme, MAX_PLAYERS,
 };
+use crate::keymap::{Control, Keymap};
 use crossterm::event::{Event, KeyCode};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    Next,
+    Prev,
+    Up,
+    Down,
+    Confirm,
+    Back,
+    Select,
+}
+
+impl Action {
+    pub fn name(self, language: Language) -> Cow<'static, str> {
+        language.text(match self {
+            Action::Next => "action.next",
+            Action::Prev => "action.prev",
+            Action::Up => "action.up",
+            Action::Down => "action.down",
+            Action::Confirm => "action.confirm",
+            Action::Back => "action.back",
+            Action::Select => "action.select",
+        })
+    }
+}
+
+/// Actions that move cursor `c` onto `index`, one die at a time
+fn moves<T>(cursor: &Cursor<T>, c: usize, index: usize) -> Option<Vec<Action>> {
+    if !cursor.is_valid(c, index) {
+        return None;
+    }
+
+    let current = cursor.cursor(c);
+    let moves = if index > current {
+        vec![
+            Action::Next;
+            (current + 1..=index)
+                .filter(|i| cursor.is_valid(c, *i))
+                .count()
+        ]
+    } else {
+        vec![Action::Prev; (index..current).filter(|i| cursor.is_valid(c, *i)).count()]
+    };
+
+    Some(moves)
+}
+
+impl<R: DiceSource> Game<R> {
+    /// Handles the input that only changes what this player sees, the help
+    /// and the leaderboard, and everything while one of them is open
+    pub fn handle_local(&mut self, event: &Event) -> bool {
+        let control = match event {
+            Event::Key(kc) => self.keymap.control(kc.code),
+            _ => None,
+        };
+
+        match control {
+            Some(Control::Leaderboard) => self.toggle_leaderboard(),
+            Some(Control::Help) => self.toggle_help(),
+            _ if self.help.is_some() || self.leaderboard.is_some() => {
+                if let Some(action) = control.and_then(Control::action) {
+                    if !self.help_action(action) {
+                        self.leaderboard_action(action);
+                    }
+                }
+            }
+            _ => return false,
+        }
+
+        self.blink = true;
+        true
+    }
 
-impl<R: Rng> Game<R> {
     pub fn handle_event(&mut self, event: Event) -> bool {
         if let Event::Key(kc) = event {
-            match (kc.code, &self.phase) {
-                (KeyCode::Right, _) => self.select_next(),
-                (KeyCode::Left, _) => self.select_prev(),
-                (KeyCode::Enter, _) => self.next_phase(),
-                (KeyCode::Esc, _) => self.prev_phase(),
-                (KeyCode::Char('q'), _) => return true,
-                (KeyCode::Char(' '), _) => self.toggle_select(),
-                (KeyCode::Up, Phase::Monster(MonsterPhase::SelectReroll(Reroll::Ally))) => {
-                    self.select_top();
-                }
-                (KeyCode::Down, Phase::Monster(MonsterPhase::SelectReroll(Reroll::Monster))) => {
-                    self.select_bottom();
+            if self.keymap.control(kc.code) == Some(Control::Quit) {
+                return true;
+            }
+        }
+        if self.handle_local(&event) {
+            return false;
+        }
+
+        match event {
+            Event::Key(kc) => match self.keymap.control(kc.code) {
+                Some(control) => {
+                    if let Some(action) = control.action() {
+                        self.play(action);
+                    }
                 }
-                (KeyCode::Up, Phase::Regroup(RegroupPhase::End)) => {
-                    self.select_continue();
+                None => {
+                    for action in self.jump_key(kc.code) {
+                        self.play(action);
+                    }
                 }
-                (KeyCode::Down, Phase::Regroup(RegroupPhase::Continue)) => {
-                    self.select_end();
+            },
+            Event::Mouse(me) => {
+                for action in self.click(me) {
+                    self.play(action);
                 }
-                _ => return false,
             }
+            // what was clickable moved, until the next frame is drawn
+            Event::Resize(..) => {
+                self.clear_hitboxes();
+                self.blink = true;
+            }
+        }
+
+        false
+    }
+
+    /// Passes `action` to whatever is on screen
+    fn play(&mut self, action: Action) {
+        // nothing can be played until the dice settle
+        if self.is_rolling() {
+            if action == Action::Confirm {
+                self.skip_roll();
+            }
+        } else if self.tutorial_allows(action) {
+            self.apply(action);
+        }
+    }
+
+    pub fn apply(&mut self, action: Action) {
+        match (action, &self.phase) {
+            (Action::Next, _) => self.select_next(),
+            (Action::Prev, _) => self.select_prev(),
+            (Action::Confirm, Phase::Start) => self.start(),
+            (Action::Confirm, Phase::Scoreboard | Phase::Victory) => self.next_turn(),
+            (
+                Action::Confirm,
+                Phase::Monster(MonsterPhase::Defeat) | Phase::Dragon(DragonPhase::Defeat),
+            ) => {
+                self.after_delve();
+            }
+            (Action::Confirm, _) => self.next_phase(),
+            (Action::Back, _) => self.prev_phase(),
+            (Action::Select, _) => self.toggle_select(),
+            (Action::Up, Phase::Monster(MonsterPhase::SelectReroll(Reroll::Ally))) => {
+                self.select_top();
+            }
+            (Action::Down, Phase::Monster(MonsterPhase::SelectReroll(Reroll::Monster))) => {
+                self.select_bottom();
+            }
+            (Action::Up, Phase::Regroup(RegroupPhase::End)) => {
+                self.select_continue();
+            }
+            (Action::Down, Phase::Regroup(RegroupPhase::Continue)) => {
+                self.select_end();
+            }
+            (Action::Up, Phase::Start) => self.select_mode(Mode::Campaign),
+            (Action::Down, Phase::Start) => self.select_mode(Mode::Classic),
+            _ => return,
         }
 
         self.blink = true;
+    }
 
-        false
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.keymap = keymap;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    /// Index of the player whose input is currently accepted, the first
+    /// player sets the game up
+    pub fn active_player(&self) -> usize {
+        match self.phase {
+            Phase::Start => 0,
+            _ => self.player,
+        }
     }
 
     fn select_next(&mut self) {
         match self.phase {
+            Phase::Start => self.selected_hero = (self.selected_hero + 1) % self.roster.len(),
             Phase::Monster(MonsterPhase::SelectAlly)
             | Phase::Loot(LootPhase::SelectAlly)
             | Phase::Dragon(DragonPhase::SelectAlly) => self.party.next(PartyCursor::Ally as usize),
@@ -54,8 +206,71 @@ impl<R: Rng> Game<R> {
         };
     }
 
+    /// Actions that move the cursor straight to the die numbered `code` in
+    /// the focused row, if it can go there, `0` being the tenth die
+    pub fn jump_key(&self, code: KeyCode) -> Vec<Action> {
+        let (KeyCode::Char(c), Some(row)) = (code, self.selected_row()) else {
+            return Vec::new();
+        };
+        if self.help.is_some() || self.leaderboard.is_some() {
+            return Vec::new();
+        }
+
+        match c.to_digit(10) {
+            Some(0) => self.jump(row, 9).unwrap_or_default(),
+            Some(n) => self.jump(row, n as usize - 1).unwrap_or_default(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Actions that move the cursor of `row` onto die `index`, switching
+    /// rows first if the phase allows it
+    pub(super) fn jump(&self, row: Row, index: usize) -> Option<Vec<Action>> {
+        let (mut actions, c) = match (&self.phase, row) {
+            (
+                Phase::Monster(MonsterPhase::SelectAlly)
+                | Phase::Loot(LootPhase::SelectAlly)
+                | Phase::Dragon(DragonPhase::SelectAlly),
+                Row::Party,
+            ) => (Vec::new(), PartyCursor::Ally as usize),
+            (
+                Phase::Monster(MonsterPhase::SelectMonster) | Phase::Loot(LootPhase::SelectLoot),
+                Row::Dungeon,
+            ) => (Vec::new(), DungeonCursor::Monster as usize),
+            (Phase::Loot(LootPhase::SelectGraveyard), Row::Graveyard) => (Vec::new(), 0),
+            // scrolls reroll from either row
+            (Phase::Monster(MonsterPhase::SelectReroll(reroll)), Row::Party) => {
+                let actions = match reroll {
+                    Reroll::Monster => vec![Action::Down],
+                    Reroll::Ally => Vec::new(),
+                };
+                (actions, PartyCursor::Reroll as usize)
+            }
+            (Phase::Monster(MonsterPhase::SelectReroll(reroll)), Row::Dungeon) => {
+                let actions = match reroll {
+                    Reroll::Ally => vec![Action::Up],
+                    Reroll::Monster => Vec::new(),
+                };
+                (actions, DungeonCursor::Reroll as usize)
+            }
+            _ => return None,
+        };
+
+        actions.extend(match row {
+            Row::Party => moves(&self.party, c, index),
+            Row::Dungeon => moves(&self.dungeon, c, index),
+            Row::Graveyard => moves(&self.graveyard, c, index),
+        }?);
+
+        Some(actions)
+    }
+
     fn select_prev(&mut self) {
         match self.phase {
+            Phase::Start => {
+                self.selected_hero =
+                    (self.selected_hero + self.roster.len() - 1) % self.roster.len();
+            }
             Phase::Monster(MonsterPhase::SelectAlly)
             | Phase::Loot(LootPhase::SelectAlly)
             | Phase::Dragon(DragonPhase::SelectAlly) => self.party.prev(PartyCursor::Ally as usize),
@@ -75,6 +290,7 @@ impl<R: Rng> Game<R> {
 
     fn toggle_select(&mut self) {
         match self.phase {
+            Phase::Start => self.toggle_player(),
             Phase::Monster(MonsterPhase::SelectReroll(ref r)) => match r {
                 Reroll::Monster => self.dungeon.toggle_select(DungeonCursor::Reroll as usize),
                 Reroll::Ally => self.party.toggle_select(PartyCursor::Reroll as usize),
@@ -102,4 +318,29 @@ impl<R: Rng> Game<R> {
     fn select_end(&mut self) {
         self.phase = Phase::Regroup(RegroupPhase::End);
     }
+
+    pub(super) fn select_mode(&mut self, mode: Mode) {
+        // the mode of a hero that has already delved is fixed, and daily
+        // challenges are always played in campaign mode
+        let hero = &mut self.roster[self.selected_hero];
+        if hero.delves() == 0 && self.daily.is_none() {
+            hero.set_mode(mode);
+        }
+    }
+
+    pub(super) fn toggle_player(&mut self) {
+        if self.daily.is_some() {
+            return;
+        }
+
+        if let Some(i) = self
+            .players
+            .iter()
+            .position(|p| p.hero == self.selected_hero)
+        {
+            self.players.remove(i);
+        } else if self.players.len() < MAX_PLAYERS {
+            self.players.push(Player::new(self.selected_hero));
+        }
+    }
 }
@@ -0,0 +1,27 @@
+use super::{DiceSource, Game, Roster, Rules};
+use crate::daily::{Daily, Run};
+
+impl<R: DiceSource> Game<R> {
+    /// Starts a single delve with a fresh hero and the standard rules, so
+    /// every attempt at the same challenge is comparable
+    pub fn with_daily(rng: R, daily: Daily) -> Self {
+        let mut game = Self::new(rng, Roster::default(), Rules::default());
+        game.daily = Some(daily);
+        game
+    }
+
+    /// The run so far, from the moment the delve starts
+    pub fn daily_run(&self) -> Option<Run> {
+        let daily = self.daily.as_ref()?;
+        if self.players.is_empty() {
+            return None;
+        }
+
+        Some(Run {
+            date: daily.date.clone(),
+            seed: daily.seed,
+            hero: self.hero().hero(),
+            score: self.hero().xp(),
+        })
+    }
+}
@@ -1,37 +1,133 @@
 use super::{
-    roll, roll_n, Ally, DragonPhase, DungeonCursor, Game, LootPhase, Monster, MonsterPhase,
-    PartyCursor, Phase, RegroupPhase, Reroll, Rng, DRAGON_ALLY_INV, LOOT_ALLY_INV,
-    LOOT_DUNGEON_INV, LOOT_SCROLL_DUNGEON_INV, MON_ALLY_INV, MON_DUNGEON_INV, TREASURE,
+    roll, roll_n, Achievement, Ally, DiceSource, DragonPhase, DungeonCursor, Ending, Game,
+    LootPhase, Mode, Monster, MonsterPhase, PartyCursor, Phase, Player, RegroupPhase, Reroll,
+    Rules, CLASSIC_PARTY_SIZE, DRAGON_ALLY_INV, LOOT_ALLY_INV, LOOT_DUNGEON_INV,
+    LOOT_SCROLL_DUNGEON_INV, MON_ALLY_INV, MON_DUNGEON_INV, TREASURE,
 };
 
-impl<R: Rng> Game<R> {
-    #[allow(clippy::cast_sign_loss)]
-    #[allow(clippy::cast_precision_loss)]
-    #[allow(clippy::cast_possible_truncation)]
+/// Number of allies a hero with `xp` starts a delve with
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn party_size(mode: Mode, xp: u64, rules: &Rules) -> u64 {
+    match mode {
+        Mode::Campaign => ((1. + 1.5 * (xp as f64 + 4.).sqrt()) as u64).min(rules.max_party_size),
+        Mode::Classic => CLASSIC_PARTY_SIZE,
+    }
+}
+
+/// Least xp a campaign hero needs to start a delve with `size` allies,
+/// `size` being at most `rules.max_party_size`
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn campaign_xp(size: u64, rules: &Rules) -> u64 {
+    // the inverse of `party_size`, nudged past any rounding error
+    let root = (size as f64 - 1.) / 1.5;
+    let mut xp = (root * root - 4.).max(0.) as u64;
+    while xp > 0 && party_size(Mode::Campaign, xp - 1, rules) >= size {
+        xp -= 1;
+    }
+    while party_size(Mode::Campaign, xp, rules) < size {
+        xp += 1;
+    }
+    xp
+}
+
+impl<R: DiceSource> Game<R> {
     pub(super) fn next_delve(&mut self) {
-        self.delve += 1;
+        self.checkpoint = true;
+        if self.hero().is_finished() {
+            self.phase = Phase::GameOver;
+            return;
+        }
+
+        self.delve = self.hero().run_delves() + 1;
         self.level = 0;
         self.run_xp = 0;
-        self.party_size = ((1. + 1.5 * (self.hero.xp() as f64 + 4.).sqrt()) as u64).min(7);
+        self.party_size = party_size(self.hero().mode(), self.hero().xp(), &self.rules);
         self.phase = Phase::Setup;
         self.party.set_data(roll_n(&mut self.rng, self.party_size));
+        self.animate_roll(&(0..self.party.len()).collect::<Vec<_>>(), &[]);
         self.graveyard.set_data(Vec::new());
+        // the dragon dice of the last delve don't follow the party
+        self.dungeon.set_data(Vec::new());
         self.treasure = TREASURE.clone();
-        self.inventory.clear();
+        self.players[self.player].inventory.clear();
         self.next_phase();
     }
 
+    pub(super) fn start(&mut self) {
+        if self.players.is_empty() {
+            self.players.push(Player::new(self.selected_hero));
+        }
+
+        for i in 0..self.players.len() {
+            let hero = self.players[i].hero;
+            self.roster[hero].new_run();
+        }
+
+        self.player = 0;
+        self.next_delve();
+    }
+
+    /// The seat that plays after the current one, skipping the players who
+    /// have won or whose hero is finished
+    pub(super) fn next_player(&self) -> Option<usize> {
+        let n = self.players.len();
+        (1..=n)
+            .map(|i| (self.player + i) % n)
+            .find(|p| !self.players[*p].won && !self.roster[self.players[*p].hero].is_finished())
+    }
+
+    pub(super) fn next_turn(&mut self) {
+        if let Some(p) = self.next_player() {
+            self.player = p;
+            self.next_delve();
+        } else {
+            self.phase = Phase::GameOver;
+        }
+    }
+
+    /// Records the delve in the history and the hero's stats, and saves the
+    /// roster
+    fn close_delve(&mut self, ending: Ending) {
+        self.record_run(ending);
+        let depth = self.level;
+        self.hero_mut().finish_delve(depth);
+        self.checkpoint = true;
+    }
+
+    fn end_delve(&mut self, ending: Ending) {
+        self.close_delve(ending);
+        self.after_delve();
+    }
+
+    /// Moves on from a delve that is over
+    pub(super) fn after_delve(&mut self) {
+        if self.daily.is_some() {
+            self.phase = Phase::GameOver;
+        } else if self.players.len() > 1 {
+            self.phase = Phase::Scoreboard;
+        } else {
+            self.next_delve();
+        }
+    }
+
     fn next_level(&mut self) {
         self.level += 1;
-        self.run_xp += self.level;
+        self.run_xp += self.level * self.rules.xp_per_level;
         self.loot_count = 0;
         self.combat_count = 0;
         self.dungeon.retain(|m| m == &Monster::Dragon);
-        let dungeon_size = self.dungeon.len() as u64;
+        let dragons = self.dungeon.len();
+        let dungeon_size = dragons as u64;
         self.dungeon.append(roll_n(
             &mut self.rng,
-            (self.delve + self.level).min(10 - dungeon_size),
+            (self.delve + self.level).min(self.rules.max_dungeon_size.saturating_sub(dungeon_size)),
         ));
+        // the dragons stay where they were
+        self.animate_roll(&[], &(dragons..self.dungeon.len()).collect::<Vec<_>>());
     }
 
     fn kill_monster(&mut self) {
@@ -43,6 +139,7 @@ impl<R: Rng> Game<R> {
         let idx = self.party.cursor(PartyCursor::Ally as usize);
         let ally = self.party.remove(idx);
         self.graveyard.push(ally);
+        self.allies_spent += 1;
     }
 
     fn execute_combat(&mut self) {
@@ -57,26 +154,34 @@ impl<R: Rng> Game<R> {
     }
 
     fn execute_reroll(&mut self) {
-        self.party
-            .selection()
-            .iter()
-            .for_each(|s| self.party.set_value(*s, roll(&mut self.rng)));
+        let party = self.party.selection();
+        for s in &party {
+            self.party.set_value(*s, roll(&mut self.rng));
+        }
         self.party.clear_selection();
 
-        self.dungeon
-            .selection()
-            .iter()
-            .for_each(|s| self.dungeon.set_value(*s, roll(&mut self.rng)));
+        let dungeon = self.dungeon.selection();
+        for s in &dungeon {
+            self.dungeon.set_value(*s, roll(&mut self.rng));
+        }
         self.dungeon.clear_selection();
 
+        let scroll = self.party.cursor(PartyCursor::Ally as usize);
         self.kill_ally();
+
+        // the dice after the scroll moved down a place
+        let party = party
+            .into_iter()
+            .map(|i| if i > scroll { i - 1 } else { i })
+            .collect::<Vec<_>>();
+        self.animate_roll(&party, &dungeon);
     }
 
     fn execute_loot(&mut self) {
         if self.affects_all() {
             self.dungeon.retain(|m| {
                 if m == &Monster::Chest {
-                    self.inventory.push(
+                    self.players[self.player].inventory.push(
                         self.treasure
                             .remove(self.rng.gen_range(0..self.treasure.len())),
                     );
@@ -86,7 +191,7 @@ impl<R: Rng> Game<R> {
                 }
             })
         } else {
-            self.inventory.push(
+            self.players[self.player].inventory.push(
                 self.treasure
                     .remove(self.rng.gen_range(0..self.treasure.len())),
             );
@@ -96,15 +201,23 @@ impl<R: Rng> Game<R> {
         self.kill_ally();
 
         self.run_xp += 1;
+        if self.inventory().len() >= 5 {
+            self.achieve(Achievement::Hoarder);
+        }
     }
 
     fn execute_graveyard(&mut self) {
         let sel = self.graveyard.selection();
         let revives = sel.len();
 
+        // removing from the back keeps the remaining indices valid
         sel.iter()
+            .rev()
             .for_each(|s| self.party.push(self.graveyard.remove(*s)));
         self.graveyard.clear_selection();
+        if revives >= 3 {
+            self.achieve(Achievement::MiracleWorker);
+        }
 
         let mut i = 0;
         self.dungeon.retain(|m| {
@@ -120,13 +233,32 @@ impl<R: Rng> Game<R> {
     }
 
     fn execute_dragon(&mut self) {
-        self.party.selection().iter().for_each(|s| {
+        let champions = self
+            .party
+            .selection()
+            .iter()
+            .any(|s| self.party[*s] == Ally::Champion);
+
+        self.party.selection().iter().rev().for_each(|s| {
             self.party.remove(*s);
+            self.allies_spent += 1;
         });
         self.party.clear_selection();
+
+        self.achieve(Achievement::DragonSlayer);
+        if !champions {
+            self.achieve(Achievement::NoChampions);
+        }
+        self.clear_level();
     }
 
-    fn enter_phase_trigger(&mut self) -> bool {
+    fn clear_level(&mut self) {
+        if self.level >= 10 {
+            self.achieve(Achievement::DeepDelver);
+        }
+    }
+
+    pub(super) fn enter_phase_trigger(&mut self) -> bool {
         match self.phase {
             Phase::Setup => {
                 self.next_level();
@@ -178,7 +310,7 @@ impl<R: Rng> Game<R> {
                 _ => (),
             },
             Phase::Dragon(DragonPhase::SelectAlly) => {
-                if self.dragon_dice() >= 3 {
+                if self.dragon_dice() as u64 >= self.rules.dragon_threshold {
                     self.party.set_invariants(DRAGON_ALLY_INV.to_vec());
                     self.party.set_selection_limit(3);
 
@@ -187,6 +319,7 @@ impl<R: Rng> Game<R> {
                         return true;
                     }
                 } else {
+                    self.clear_level();
                     self.phase = Phase::Regroup(RegroupPhase::Continue);
                     return true;
                 }
@@ -196,13 +329,17 @@ impl<R: Rng> Game<R> {
                 return true;
             }
             Phase::Regroup(RegroupPhase::EndSetup) => {
-                self.hero.add_xp(self.run_xp);
-                self.next_delve();
+                let xp = self.run_xp;
+                self.hero_mut().add_xp(xp);
+                if self.party.is_empty() {
+                    self.achieve(Achievement::LastOneOut);
+                }
+                self.end_delve(Ending::Retired);
                 return true;
             }
-            Phase::Monster(MonsterPhase::Defeat) | Phase::Dragon(DragonPhase::Defeat) => {
-                self.next_delve()
-            }
+            // the defeat stays on screen until the player moves on
+            Phase::Monster(MonsterPhase::Defeat) => self.close_delve(Ending::Monsters),
+            Phase::Dragon(DragonPhase::Defeat) => self.close_delve(Ending::Dragon),
             _ => (),
         }
         false
@@ -220,6 +357,10 @@ impl<R: Rng> Game<R> {
             Phase::Dragon(DragonPhase::Confirm) => {
                 self.execute_dragon();
                 self.party.set_selection_limit(0);
+                let xp = self.run_xp;
+                self.hero_mut().add_xp(xp);
+                self.close_delve(Ending::Victory);
+                self.players[self.player].won = true;
             }
             _ => (),
         }
@@ -228,6 +369,7 @@ impl<R: Rng> Game<R> {
     pub(super) fn next_phase(&mut self) {
         self.exit_phase_trigger();
         self.phase = match self.phase {
+            Phase::Start => Phase::Start,
             Phase::Setup => Phase::Setup,
             Phase::Monster(ref mp) => match mp {
                 MonsterPhase::SelectAlly => {
@@ -285,8 +427,11 @@ impl<R: Rng> Game<R> {
                 Phase::Regroup(RegroupPhase::EndSetup)
             }
             Phase::Victory => Phase::Victory,
+            Phase::Scoreboard => Phase::Scoreboard,
+            Phase::GameOver => Phase::GameOver,
         };
         while self.enter_phase_trigger() {}
+        self.check_goal();
     }
 
     pub(super) fn prev_phase(&mut self) {
@@ -315,6 +460,7 @@ impl<R: Rng> Game<R> {
         } {
             self.phase = p;
             while self.enter_phase_trigger() {}
+            self.check_goal();
         }
     }
 }
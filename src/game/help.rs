@@ -0,0 +1,62 @@
+use super::{Action, DiceSource, Game};
+
+/// Catalog keys of the page names
+pub(super) const PAGES: [&str; 6] = [
+    "help.pages.allies",
+    "help.pages.monsters",
+    "help.pages.treasure",
+    "help.pages.heroes",
+    "help.pages.phases",
+    "help.pages.scoring",
+];
+
+pub(super) struct Help {
+    pub(super) page: usize,
+    pub(super) scroll: u16,
+}
+
+impl<R: DiceSource> Game<R> {
+    /// Opens the help on its first page, or closes it leaving the game as it
+    /// was
+    pub(super) fn toggle_help(&mut self) {
+        self.help = match self.help {
+            Some(_) => None,
+            None => Some(Help { page: 0, scroll: 0 }),
+        };
+    }
+
+    /// Handles `action` if the help is open
+    pub(super) fn help_action(&mut self, action: Action) -> bool {
+        let Some(help) = &self.help else {
+            return false;
+        };
+        let (page, scroll) = (help.page, help.scroll);
+
+        let n = PAGES.len();
+        self.help = match action {
+            Action::Next => Some(Help {
+                page: (page + 1) % n,
+                scroll: 0,
+            }),
+            Action::Prev => Some(Help {
+                page: (page + n - 1) % n,
+                scroll: 0,
+            }),
+            Action::Up => Some(Help {
+                page,
+                scroll: scroll.saturating_sub(1),
+            }),
+            Action::Down => {
+                let last = self.help_page(page).height().saturating_sub(1);
+                Some(Help {
+                    page,
+                    scroll: (scroll + 1).min(u16::try_from(last).unwrap_or(u16::MAX)),
+                })
+            }
+            Action::Confirm | Action::Back => None,
+            Action::Select => Some(Help { page, scroll }),
+        };
+
+        true
+    }
+}
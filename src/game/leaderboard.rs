@@ -0,0 +1,94 @@
+use super::{Action, DiceSource, Game};
+use crate::{
+    hero::Type,
+    history::{Ending, History, Run, Sort},
+};
+
+pub(super) struct Leaderboard {
+    pub(super) sort: Sort,
+    pub(super) filter: Option<Type>,
+}
+
+fn cycle<T: Copy + PartialEq>(items: &[T], current: T, forward: bool) -> T {
+    let i = items.iter().position(|t| *t == current).unwrap_or(0);
+    let n = items.len();
+    items[if forward {
+        (i + 1) % n
+    } else {
+        (i + n - 1) % n
+    }]
+}
+
+impl<R: DiceSource> Game<R> {
+    /// Records the outcome of every delve from now on into `history`
+    pub fn track_history(&mut self, history: History, seed: u64) {
+        self.history = Some(history);
+        self.seed = seed;
+    }
+
+    /// Returns the history if a delve was recorded since the last call
+    pub fn take_history(&mut self) -> Option<&History> {
+        if self.history_changed {
+            self.history_changed = false;
+            self.history.as_ref()
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn record_run(&mut self, ending: Ending) {
+        let run = Run {
+            hero: self.hero().name().to_string(),
+            hero_type: self.hero().hero(),
+            seed: self.seed,
+            delve: self.delve,
+            level: self.level,
+            xp: match ending {
+                Ending::Retired | Ending::Victory => self.run_xp,
+                Ending::Monsters | Ending::Dragon => 0,
+            },
+            treasure: self.inventory().len() as u64,
+            ending,
+        };
+
+        if let Some(history) = &mut self.history {
+            history.push(run);
+            self.history_changed = true;
+        }
+    }
+
+    pub(super) fn toggle_leaderboard(&mut self) {
+        // closes it when open, and can't open it without a history
+        self.leaderboard = match self.leaderboard {
+            None if self.history.is_some() => Some(Leaderboard {
+                sort: Sort::Score,
+                filter: None,
+            }),
+            _ => None,
+        };
+    }
+
+    /// Handles `action` if the leaderboard is open
+    pub(super) fn leaderboard_action(&mut self, action: Action) -> bool {
+        let Some(board) = &mut self.leaderboard else {
+            return false;
+        };
+
+        let filters = [None]
+            .into_iter()
+            .chain(Type::ALL.map(Some))
+            .collect::<Vec<_>>();
+        match action {
+            Action::Next | Action::Prev => {
+                board.filter = cycle(&filters, board.filter, action == Action::Next);
+            }
+            Action::Up | Action::Down => {
+                board.sort = cycle(&Sort::ALL, board.sort, action == Action::Down);
+            }
+            Action::Confirm | Action::Back => self.leaderboard = None,
+            Action::Select => (),
+        }
+
+        true
+    }
+}
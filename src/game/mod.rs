@@ -1,19 +1,49 @@
 use crate::{
-    dice::{Ally, Dice, Monster, Render},
+    achievements::{Achievement, Achievements},
+    daily::Daily,
+    dice::{describe, Ally, Dice, Monster, Render},
+    dice_source::DiceSource,
     hero::{Hero, Type},
+    history::{Ending, History},
+    keymap::{Control, Keymap},
+    locale::Language,
     phase::{
         Dragon as DragonPhase, Loot as LootPhase, Monster as MonsterPhase, Phase,
         Regroup as RegroupPhase, Reroll,
     },
+    roster::Roster,
+    rules::{Mode, Rules, CLASSIC_DELVES, CLASSIC_PARTY_SIZE},
+    scenario::{Goal, GoalStatus},
+    theme::Theme,
     treasure::{Treasure, TREASURE},
 };
-use rand::prelude::*;
+use std::{cell::RefCell, collections::VecDeque};
+use tui::layout::Rect;
 
+mod achievements;
+mod animation;
+mod command;
 mod controls;
+mod daily;
 mod gameplay;
+mod help;
+mod leaderboard;
+mod mouse;
 mod render;
+mod scenario;
+mod text;
+mod tutorial;
 mod utils;
 
+use animation::Roll;
+pub use animation::ROLL_FRAMES;
+pub use command::{Command, Observation};
+pub use controls::Action;
+use gameplay::{campaign_xp, party_size};
+use help::{Help, PAGES};
+use leaderboard::Leaderboard;
+use mouse::Hitbox;
+pub use text::HELP;
 use utils::{indexes_of, roll, roll_n, Cursor, Invariant, Row};
 
 lazy_static! {
@@ -54,7 +84,27 @@ enum DungeonCursor {
     Reroll = 1,
 }
 
-pub struct Game<R: Rng> {
+pub const MAX_PLAYERS: usize = 4;
+
+struct Player {
+    hero: usize,
+    inventory: Vec<Treasure>,
+    /// Slew the dragon, the player sits out the rest of the game
+    won: bool,
+}
+
+impl Player {
+    fn new(hero: usize) -> Self {
+        Self {
+            hero,
+            inventory: Vec::new(),
+            won: false,
+        }
+    }
+}
+
+#[allow(clippy::struct_excessive_bools)]
+pub struct Game<R: DiceSource> {
     rng: R,
     blink: bool,
     delve: u64,
@@ -64,17 +114,41 @@ pub struct Game<R: Rng> {
     loot_count: u64,
     combat_count: u64,
     phase: Phase,
-    hero: Hero,
+    rules: Rules,
+    checkpoint: bool,
+    goal: Option<Goal>,
+    daily: Option<Daily>,
+    seed: u64,
+    history: Option<History>,
+    history_changed: bool,
+    leaderboard: Option<Leaderboard>,
+    achievements: Option<Achievements>,
+    achievements_changed: bool,
+    toasts: VecDeque<Achievement>,
+    toast_ticks: u8,
+    tutorial: Option<usize>,
+    help: Option<Help>,
+    keymap: Keymap,
+    theme: Theme,
+    language: Language,
+    animations: bool,
+    roll: Option<Roll>,
+    hitboxes: RefCell<Vec<(Rect, Hitbox)>>,
+    goal_status: GoalStatus,
+    allies_spent: u64,
+    roster: Roster,
+    selected_hero: usize,
+    players: Vec<Player>,
+    player: usize,
     party: Cursor<Ally>,
     dungeon: Cursor<Monster>,
     graveyard: Cursor<Ally>,
     treasure: Vec<Treasure>,
-    inventory: Vec<Treasure>,
 }
 
-impl<R: Rng> Game<R> {
-    pub fn new(rng: R, hero: Type) -> Self {
-        let mut game = Self {
+impl<R: DiceSource> Game<R> {
+    pub fn new(rng: R, roster: Roster, rules: Rules) -> Self {
+        Self {
             rng,
             blink: true,
             delve: 0,
@@ -83,21 +157,79 @@ impl<R: Rng> Game<R> {
             loot_count: 0,
             combat_count: 0,
             party_size: 0,
-            phase: Phase::Setup,
-            hero: Hero::new(hero),
+            phase: Phase::Start,
+            rules,
+            checkpoint: false,
+            goal: None,
+            daily: None,
+            seed: 0,
+            history: None,
+            history_changed: false,
+            leaderboard: None,
+            achievements: None,
+            achievements_changed: false,
+            toasts: VecDeque::new(),
+            toast_ticks: 0,
+            tutorial: None,
+            help: None,
+            keymap: Keymap::default(),
+            theme: Theme::default(),
+            language: Language::default(),
+            animations: false,
+            roll: None,
+            hitboxes: RefCell::new(Vec::new()),
+            goal_status: GoalStatus::Pending,
+            allies_spent: 0,
+            roster,
+            selected_hero: 0,
+            players: Vec::new(),
+            player: 0,
             party: Cursor::new(Vec::new(), MON_ALLY_INV.to_vec()),
             graveyard: Cursor::new(Vec::new(), vec![|_, _, _| true]),
             dungeon: Cursor::new(Vec::new(), MON_DUNGEON_INV.to_vec()),
             treasure: TREASURE.clone(),
-            inventory: Vec::new(),
-        };
-
-        game.next_delve();
+        }
+    }
 
-        game
+    pub fn phase(&self) -> &Phase {
+        &self.phase
     }
 
     pub fn toggle_blink(&mut self) {
         self.blink = !self.blink;
+        self.tick_toasts();
+    }
+
+    /// Where the dice come from, the server reads back what each action drew
+    pub(crate) fn rng_mut(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    /// Returns the roster if the campaign progress changed since the last call
+    pub fn take_checkpoint(&mut self) -> Option<&Roster> {
+        // scenario, daily and tutorial games don't play the stored roster
+        let scratch = self.goal.is_some() || self.daily.is_some() || self.tutorial.is_some();
+        if self.checkpoint && !scratch {
+            self.checkpoint = false;
+            Some(&self.roster)
+        } else {
+            None
+        }
+    }
+
+    fn hero(&self) -> &Hero {
+        &self.roster[self.players[self.player].hero]
+    }
+
+    fn hero_mut(&mut self) -> &mut Hero {
+        &mut self.roster[self.players[self.player].hero]
+    }
+
+    fn inventory(&self) -> &[Treasure] {
+        &self.players[self.player].inventory
+    }
+
+    fn setup_hero(&self) -> &Hero {
+        &self.roster[self.selected_hero]
     }
 }
@@ -0,0 +1,71 @@
+use super::{
+    Action, DiceSource, DragonPhase, Game, LootPhase, MonsterPhase, Phase, RegroupPhase, Row,
+};
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use tui::layout::Rect;
+
+/// Something drawn on the last frame that can be clicked
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Hitbox {
+    Die(Row, usize),
+    Continue,
+    End,
+}
+
+impl<R: DiceSource> Game<R> {
+    /// Translates a mouse event into the actions the keyboard would need to
+    /// do the same, so clicks go through the same checks as key presses
+    pub fn click(&self, event: MouseEvent) -> Vec<Action> {
+        if event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Vec::new();
+        }
+
+        let (x, y) = (event.column, event.row);
+        let hit = self.hitboxes.borrow().iter().find_map(|(r, hit)| {
+            let inside = x >= r.x && x < r.x + r.width && y >= r.y && y < r.y + r.height;
+            inside.then_some(*hit)
+        });
+
+        match (hit, &self.phase) {
+            (Some(Hitbox::Die(row, index)), _) => self.click_die(row, index),
+            (Some(Hitbox::Continue), Phase::Regroup(RegroupPhase::Continue))
+            | (Some(Hitbox::End), Phase::Regroup(RegroupPhase::End)) => vec![Action::Confirm],
+            (Some(Hitbox::Continue), Phase::Regroup(RegroupPhase::End)) => vec![Action::Up],
+            (Some(Hitbox::End), Phase::Regroup(RegroupPhase::Continue)) => vec![Action::Down],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Clicking a die moves the cursor onto it, clicking it again marks it
+    /// or confirms it
+    fn click_die(&self, row: Row, index: usize) -> Vec<Action> {
+        let Some(mut actions) = self.jump(row, index) else {
+            return Vec::new();
+        };
+
+        if actions.is_empty() {
+            let marks = matches!(
+                self.phase,
+                Phase::Monster(MonsterPhase::SelectReroll(_))
+                    | Phase::Loot(LootPhase::SelectGraveyard)
+                    | Phase::Dragon(DragonPhase::SelectAlly)
+            );
+            actions.push(if marks {
+                Action::Select
+            } else {
+                Action::Confirm
+            });
+        }
+
+        actions
+    }
+
+    /// Forgets what was clickable, before a new frame is drawn
+    pub(super) fn clear_hitboxes(&self) {
+        self.hitboxes.borrow_mut().clear();
+    }
+
+    pub(super) fn add_hitbox(&self, area: Rect, hitbox: Hitbox) {
+        self.hitboxes.borrow_mut().push((area, hitbox));
+    }
+}
@@ -1,6 +1,8 @@
 use super::{
-    indexes_of, Ally, DragonPhase, DungeonCursor, Game, LootPhase, Monster, MonsterPhase,
-    PartyCursor, Phase, RegroupPhase, Render, Reroll, Rng, Row,
+    campaign_xp, describe, indexes_of, party_size, Ally, Control, Dice, DiceSource, DragonPhase,
+    DungeonCursor, Game, GoalStatus, Help, Hitbox, Language, Leaderboard, LootPhase, Mode, Monster,
+    MonsterPhase, PartyCursor, Phase, RegroupPhase, Render, Reroll, Row, Rules, Theme, Treasure,
+    Type, CLASSIC_DELVES, PAGES, TREASURE,
 };
 use std::{io, iter::repeat, ops::ControlFlow};
 use tui::{
@@ -9,12 +11,49 @@ use tui::{
     style::{Color, Modifier, Style},
     terminal::{CompletedFrame, Frame},
     text::{Span, Spans, Text},
-    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row as TableRow, Table, Wrap},
     Terminal,
 };
 
 static DRAGON: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/dragon.txt"));
 
+/// Below this nothing but a warning is drawn
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 16;
+/// Below this the info panel is dropped and the margins with it
+const COMPACT_WIDTH: u16 = 80;
+const COMPACT_HEIGHT: u16 = 30;
+
+fn too_small(size: Rect) -> bool {
+    size.width < MIN_WIDTH || size.height < MIN_HEIGHT
+}
+
+#[allow(clippy::non_ascii_literal)]
+fn render_too_small<B: Backend>(f: &mut Frame<B>, language: Language) {
+    let size = f.size();
+    let text = Text::from(vec![
+        Spans::from(Span::styled(
+            language.text("terminal.too_small"),
+            *TITLE_STYLE,
+        )),
+        Spans::from(format!("{}×{}", size.width, size.height)),
+        Spans::from(language.format(
+            "terminal.needs",
+            &[
+                ("width", MIN_WIDTH.to_string()),
+                ("height", MIN_HEIGHT.to_string()),
+            ],
+        )),
+    ]);
+
+    f.render_widget(
+        Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        vertical_center(size, 3),
+    );
+}
+
 lazy_static! {
     static ref TITLE_STYLE: Style =
         Style::default().add_modifier(Modifier::UNDERLINED | Modifier::BOLD);
@@ -37,16 +76,88 @@ fn vertical_center(area: Rect, height: u16) -> Rect {
     }
 }
 
+/// The symbol a die is drawn with, ahead of its description
+fn help_entry(symbol: Span<'static>, info: &Spans) -> Spans<'static> {
+    let mut spans = vec![symbol, Span::raw("  ")];
+    spans.extend(owned(info).0);
+    Spans::from(spans)
+}
+
+fn help_allies(theme: Theme, language: Language) -> Vec<Spans<'static>> {
+    let mut lines = Vec::new();
+    for ally in (0..Ally::faces()).map(Ally::nth) {
+        let symbol = Span::styled(ally.symbol(), ally.style(theme));
+        lines.push(help_entry(symbol, &ally.combat_info(theme, language)));
+        lines.push(help_entry(Span::raw(" "), &ally.loot_info(theme, language)));
+        lines.push(help_entry(Span::raw(" "), &ally.flavor_text(language)));
+        lines.push(Spans::from(""));
+    }
+
+    lines
+}
+
+fn help_monsters(rules: &Rules, theme: Theme, language: Language) -> Vec<Spans<'static>> {
+    let mut lines = Vec::new();
+    for monster in (0..Monster::faces()).map(Monster::nth) {
+        let symbol = Span::styled(monster.symbol(), monster.style(theme));
+        if monster == Monster::Dragon {
+            let threshold = rules.dragon_threshold.to_string();
+            let info = describe(
+                language,
+                theme,
+                "monster.dragon.help",
+                &[("threshold", threshold)],
+            );
+            lines.push(help_entry(symbol, &info));
+        } else {
+            lines.push(help_entry(symbol, &monster.combat_info(theme, language)));
+            lines.push(help_entry(Span::raw(" "), &monster.flavor_text(language)));
+        }
+        lines.push(Spans::from(""));
+    }
+
+    lines
+}
+
+fn owned(spans: &Spans) -> Spans<'static> {
+    Spans::from(
+        spans
+            .0
+            .iter()
+            .map(|s| Span::styled(s.content.to_string(), s.style))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Number of lines `text` takes once wrapped at word boundaries to `width`
+fn wrapped_lines(text: &str, width: usize) -> usize {
+    let mut lines = 1;
+    let mut line = 0;
+    for word in text.split_whitespace() {
+        let len = word.chars().count();
+        if line > 0 && line + 1 + len > width {
+            lines += 1;
+            line = len;
+        } else {
+            line += len + usize::from(line > 0);
+        }
+    }
+
+    lines
+}
+
 fn render_block<B: Backend>(f: &mut Frame<B>, block: Block, area: Rect) -> Rect {
     let inner = block.inner(area);
     f.render_widget(block, area);
     inner
 }
 
-fn render_dragon<B: Backend>(f: &mut Frame<B>, area: Rect) {
+fn render_dragon<B: Backend>(f: &mut Frame<B>, area: Rect, language: Language) {
     let display_area = render_block(
         f,
-        Block::default().title(" Dragon ").borders(Borders::ALL),
+        Block::default()
+            .title(format!(" {} ", language.text("playfield.dragon")))
+            .borders(Borders::ALL),
         area,
     );
     let chunks = Layout::default()
@@ -60,7 +171,22 @@ fn render_dragon<B: Backend>(f: &mut Frame<B>, area: Rect) {
 
 fn render_list<B: Backend>(f: &mut Frame<B>, block: Block, area: Rect, data: &[&str], rows: usize) {
     let text_area = render_block(f, block, area);
-    let columns = (data.len() + 1) / rows;
+    if data.is_empty() {
+        return;
+    }
+
+    // entries that don't fit side by side are spread over more rows, as long
+    // as there's room for them
+    let widest = data.iter().map(|d| d.chars().count()).max().unwrap_or(0);
+    let mut rows = rows.clamp(1, data.len());
+    while rows < data.len()
+        && rows < usize::from(text_area.height)
+        && usize::from(text_area.width) / data.len().div_ceil(rows) <= widest
+    {
+        rows += 1;
+    }
+
+    let columns = data.len().div_ceil(rows);
     let column = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
@@ -71,11 +197,10 @@ fn render_list<B: Backend>(f: &mut Frame<B>, block: Block, area: Rect, data: &[&
         .split(text_area);
 
     column.iter().enumerate().for_each(|(i, col)| {
-        let ratio = if i == rows - 1 {
-            data.len() - i * columns
-        } else {
-            columns
-        };
+        let ratio = data.len().saturating_sub(i * columns).min(columns);
+        if ratio == 0 {
+            return;
+        }
 
         let row = Layout::default()
             .direction(Direction::Horizontal)
@@ -100,143 +225,121 @@ fn render_list<B: Backend>(f: &mut Frame<B>, block: Block, area: Rect, data: &[&
     });
 }
 
-fn phase_info(phase: &Phase) -> Text {
-    match phase {
-        Phase::Monster(ref mp) => {
-            let mut lines = vec![
-                Spans::from(Span::styled("Monster Phase", *TITLE_STYLE)),
-                Spans::from(""),
-            ];
-
-            let mut extra = match mp {
-                MonsterPhase::SelectAlly => vec![
-                    Spans::from(vec![
-                        Span::raw("A. Use a "),
-                        Span::styled("Scroll", Ally::Scroll.style()),
-                        Span::raw(" to re-roll dice"),
-                    ]),
-                    Spans::from("B. Use a Companion to defeat one or more Monsters"),
-                    Spans::from(""),
-                    Spans::from(""),
-                    Spans::from(vec![
-                        Span::raw("If there are three or more "),
-                        Span::styled("Dragon", Monster::Dragon.style()),
-                        Span::raw(" dice, the "),
-                        Span::styled("Dragon", Monster::Dragon.style()),
-                        Span::raw(" notice your presence and attack"),
-                    ]),
-                ],
-                MonsterPhase::SelectMonster => vec![Spans::from("Select a Monster to fight")],
-                MonsterPhase::ConfirmCombat => vec![Spans::from("Confirm the combat")],
-                MonsterPhase::SelectReroll(_) => vec![Spans::from("Select dice to re-roll")],
-                MonsterPhase::ConfirmReroll => vec![Spans::from("Confirm the re-roll")],
-                MonsterPhase::Defeat => {
-                    lines = vec![
-                        Spans::from(Span::styled("Defeat!", *TITLE_STYLE)),
-                        Spans::from(""),
-                    ];
+fn render_log<B: Backend>(f: &mut Frame<B>, area: Rect, log: &[String], language: Language) {
+    let log_area = render_block(
+        f,
+        Block::default()
+            .title(format!(" {} ", language.text("playfield.log")))
+            .borders(Borders::ALL),
+        area,
+    );
 
-                    vec![
-                        Spans::from("The Monsters in this dungeon proved far too strong for the adventurers"),
-                        Spans::from(""),
-                        Spans::from("Next expedition will require more wary decision-making or simply more luck"),
-                    ]
-                }
-            };
+    let shown = usize::from(log_area.height);
+    let lines = log
+        .iter()
+        .skip(log.len().saturating_sub(shown))
+        .map(|l| Spans::from(l.as_str()))
+        .collect::<Vec<_>>();
 
-            lines.append(&mut extra);
-            Text::from(lines)
-        }
-        Phase::Loot(ref lp) => {
-            let mut lines = vec![
-                Spans::from(Span::styled("Loot Phase", *TITLE_STYLE)),
-                Spans::from(""),
-            ];
-
-            let mut extra = match lp {
-                LootPhase::SelectAlly => vec![
-                    Spans::from(vec![
-                        Span::raw("A. Open "),
-                        Span::styled("Chests", Monster::Chest.style()),
-                    ]),
-                    Spans::from(""),
-                    Spans::from(vec![
-                        Span::raw("A. Quaff "),
-                        Span::styled("Potions", Monster::Potion.style()),
-                    ]),
-                ],
-                _ => vec![Spans::from("")],
-            };
+    f.render_widget(Paragraph::new(Text::from(lines)), log_area);
+}
 
-            lines.append(&mut extra);
-            Text::from(lines)
-        }
-        Phase::Dragon(DragonPhase::Defeat) => Text::from(vec![
-            Spans::from(Span::styled("Defeat!", *TITLE_STYLE)),
-            Spans::from(""),
-            Spans::from(vec![
-                Span::raw("The mighty "),
-                Span::styled("Dragon", Monster::Dragon.style()),
-                Span::raw(" has defended its dungeon once again"),
-            ]),
-            Spans::from(""),
-            Spans::from(
-                "Next expedition will require more wary decision-making or simply more luck",
+fn phase_info(phase: &Phase, rules: &Rules, theme: Theme, language: Language) -> Text<'static> {
+    let title = |key| Spans::from(Span::styled(language.text(key), *TITLE_STYLE));
+    let line = |key| describe(language, theme, key, &[]);
+    let blank = || Spans::from("");
+
+    let lines = match phase {
+        Phase::Monster(MonsterPhase::Defeat) => vec![
+            title("phase.defeat.title"),
+            blank(),
+            line("phase.defeat.monsters"),
+            blank(),
+            line("phase.defeat.retry"),
+        ],
+        Phase::Monster(MonsterPhase::SelectAlly) => vec![
+            title("phase.monster.title"),
+            blank(),
+            line("phase.monster.scroll"),
+            line("phase.monster.companion"),
+            blank(),
+            blank(),
+            describe(
+                language,
+                theme,
+                "phase.monster.dragon",
+                &[("threshold", rules.dragon_threshold.to_string())],
             ),
-        ]),
-        Phase::Dragon(_) => Text::from(vec![
-            Spans::from(Span::styled("Dragon Phase", *TITLE_STYLE)),
-            Spans::from(""),
-            Spans::from(vec![
-                Span::raw("You have awakened the "),
-                Span::styled("Dragon", Monster::Dragon.style()),
-                Span::raw(" you must select 3 Party members to defeat it"),
-            ]),
-        ]),
-        Phase::EmptyDungeon => Text::from(vec![
-            Spans::from(Span::styled("Empty Dungeon", *TITLE_STYLE)),
-            Spans::from(""),
-            Spans::from("It seems this level of the dungeon is empty"),
-            Spans::from(""),
-            Spans::from("Better to move on swiftly before anyone notices"),
-        ]),
-        Phase::Regroup(ref rp) => {
-            let mut lines = vec![
-                Spans::from(Span::styled("Regroup Phase", *TITLE_STYLE)),
-                Spans::from(""),
-            ];
-
-            let mut extra = match rp {
-                RegroupPhase::Continue => vec![
-                    Spans::from("Delve deeper into the dungeon"),
-                    Spans::from(""),
-                    Spans::from("If you die in the dungeon, you will not receive any XP"),
-                    Spans::from(""),
-                    Spans::from("Make sure you have enough Party members to keep going"),
-                ],
-                _ => vec![
-                    Spans::from("Exit the dungeon safely and live to tell the tale"),
-                    Spans::from(""),
-                    Spans::from(
-                        "You will receive extra dice based on your XP and start a new delve",
-                    ),
-                ],
+        ],
+        Phase::Monster(mp) => {
+            let key = match mp {
+                MonsterPhase::SelectMonster => "phase.monster.select_monster",
+                MonsterPhase::ConfirmCombat => "phase.monster.confirm_combat",
+                MonsterPhase::SelectReroll(_) => "phase.monster.select_reroll",
+                _ => "phase.monster.confirm_reroll",
             };
-
-            lines.append(&mut extra);
-            Text::from(lines)
+            vec![title("phase.monster.title"), blank(), line(key)]
         }
-        _ => Text::from(""),
-    }
+        Phase::Loot(LootPhase::SelectAlly) => vec![
+            title("phase.loot.title"),
+            blank(),
+            line("phase.loot.chests"),
+            blank(),
+            line("phase.loot.potions"),
+        ],
+        Phase::Loot(_) => vec![title("phase.loot.title"), blank(), blank()],
+        Phase::Dragon(DragonPhase::Defeat) => vec![
+            title("phase.defeat.title"),
+            blank(),
+            line("phase.defeat.dragon"),
+            blank(),
+            line("phase.defeat.retry"),
+        ],
+        Phase::Dragon(_) => vec![
+            title("phase.dragon.title"),
+            blank(),
+            line("phase.dragon.awakened"),
+        ],
+        Phase::EmptyDungeon => vec![
+            title("phase.empty.title"),
+            blank(),
+            line("phase.empty.empty"),
+            blank(),
+            line("phase.empty.move_on"),
+        ],
+        Phase::Regroup(RegroupPhase::Continue) => vec![
+            title("phase.regroup.title"),
+            blank(),
+            line("phase.regroup.continue"),
+            blank(),
+            line("phase.regroup.no_xp"),
+            blank(),
+            line("phase.regroup.party"),
+        ],
+        Phase::Regroup(RegroupPhase::End) => vec![
+            title("phase.regroup.title"),
+            blank(),
+            line("phase.regroup.end"),
+            blank(),
+            line("phase.regroup.reward"),
+        ],
+        _ => vec![blank()],
+    };
+
+    Text::from(lines)
 }
 
-impl<R: Rng> Game<R> {
+impl<R: DiceSource> Game<R> {
     fn render_info<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
-        let info_area = render_block(
-            f,
-            Block::default().title(" Info ").borders(Borders::ALL),
-            area,
-        );
+        let title = match self.goal {
+            Some(goal) => format!(
+                " {} ",
+                self.language
+                    .format("playfield.goal", &[("goal", goal.describe(self.language))])
+            ),
+            None => format!(" {} ", self.language.text("playfield.info")),
+        };
+        let info_area = render_block(f, Block::default().title(title).borders(Borders::ALL), area);
 
         let chunks = Layout::default()
             .vertical_margin(1)
@@ -252,29 +355,44 @@ impl<R: Rng> Game<R> {
             .split(info_area);
 
         f.render_widget(
-            Paragraph::new(phase_info(&self.phase)).wrap(Wrap { trim: true }),
+            Paragraph::new(phase_info(
+                &self.phase,
+                &self.rules,
+                self.theme,
+                self.language,
+            ))
+            .wrap(Wrap { trim: true }),
             chunks[0],
         );
 
+        // the result isn't given away before the dice settle
         let character_info = match (self.selected_row(), &self.phase) {
-            (Some(Row::Dungeon), _) if !self.dungeon.is_empty() => {
-                self.current_monster().combat_info()
-            }
+            _ if self.is_rolling() => Spans::from(""),
+            (Some(Row::Dungeon), _) if !self.dungeon.is_empty() => self
+                .current_monster()
+                .combat_info(self.theme, self.language),
             (Some(Row::Party), Phase::Loot(_)) if !self.party.is_empty() => {
-                self.current_ally().loot_info()
+                self.current_ally().loot_info(self.theme, self.language)
             }
-            (Some(Row::Party), _) if !self.party.is_empty() => self.current_ally().combat_info(),
-            (Some(Row::Graveyard), _) if !self.graveyard.is_empty() => {
-                self.current_graveyard().combat_info()
+            (Some(Row::Party), _) if !self.party.is_empty() => {
+                self.current_ally().combat_info(self.theme, self.language)
             }
+            (Some(Row::Graveyard), _) if !self.graveyard.is_empty() => self
+                .current_graveyard()
+                .combat_info(self.theme, self.language),
             _ => Spans::from(""),
         };
 
         let character_flavor = match self.selected_row() {
-            Some(Row::Dungeon) if !self.dungeon.is_empty() => self.current_monster().flavor_text(),
-            Some(Row::Party) if !self.party.is_empty() => self.current_ally().flavor_text(),
+            _ if self.is_rolling() => Spans::from(""),
+            Some(Row::Dungeon) if !self.dungeon.is_empty() => {
+                self.current_monster().flavor_text(self.language)
+            }
+            Some(Row::Party) if !self.party.is_empty() => {
+                self.current_ally().flavor_text(self.language)
+            }
             Some(Row::Graveyard) if !self.graveyard.is_empty() => {
-                self.current_graveyard().flavor_text()
+                self.current_graveyard().flavor_text(self.language)
             }
             _ => Spans::from(""),
         };
@@ -290,63 +408,598 @@ impl<R: Rng> Game<R> {
         );
     }
 
-    #[allow(clippy::non_ascii_literal)]
-    fn render_controls<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
-        let mut controls = vec!["→: Next", "←: Previous"];
+    /// `label` after the keys bound to `controls`, as shown in a controls bar
+    /// The keys bound to `controls` ahead of the text for `key`
+    fn control(&self, controls: &[Control], key: &str) -> String {
+        let keys = controls
+            .iter()
+            .map(|c| self.keymap.label(*c))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("{keys}: {}", self.language.text(key))
+    }
+
+    /// The controls of the phase on screen
+    fn phase_controls(&self) -> Vec<String> {
+        use Control::{Back, Confirm, Down, Next, Prev, Select, Up};
+        let c = |controls: &[Control], key: &str| self.control(controls, key);
+
+        let mut controls = vec![c(&[Next], "controls.next"), c(&[Prev], "controls.previous")];
         match self.phase {
             Phase::Monster(MonsterPhase::Defeat) | Phase::Dragon(DragonPhase::Defeat) => {
-                controls.append(&mut vec!["Enter: Next delve"]);
+                controls.push(c(&[Confirm], "controls.next_delve"));
             }
             Phase::Monster(ref mp) => match mp {
                 MonsterPhase::SelectReroll(Reroll::Ally) => controls.append(&mut vec![
-                    "↑: Dungeon row",
-                    "Space: Select",
-                    "Enter: Confirm",
-                    "Esc: Back",
+                    c(&[Up], "controls.dungeon_row"),
+                    c(&[Select], "controls.select"),
+                    c(&[Confirm], "controls.confirm"),
+                    c(&[Back], "controls.back"),
                 ]),
                 MonsterPhase::ConfirmCombat | MonsterPhase::ConfirmReroll => {
-                    controls = vec!["Enter: Confirm", "Esc: Back"];
+                    controls = vec![
+                        c(&[Confirm], "controls.confirm"),
+                        c(&[Back], "controls.back"),
+                    ];
                 }
                 MonsterPhase::SelectReroll(Reroll::Monster) => controls.append(&mut vec![
-                    "↓: Party row",
-                    "Space: Select",
-                    "Enter: Confirm",
-                    "Esc: Back",
+                    c(&[Down], "controls.party_row"),
+                    c(&[Select], "controls.select"),
+                    c(&[Confirm], "controls.confirm"),
+                    c(&[Back], "controls.back"),
+                ]),
+                _ => controls.append(&mut vec![
+                    c(&[Confirm], "controls.select"),
+                    c(&[Back], "controls.back"),
                 ]),
-                _ => controls.append(&mut vec!["Enter: Select", "Esc: Back"]),
             },
             Phase::Loot(ref lp) => controls.append(&mut vec![
-                "Enter: Confirm",
+                c(&[Confirm], "controls.confirm"),
                 match lp {
-                    LootPhase::SelectAlly => "Esc: Skip loot",
-                    _ => "Esc: Back",
+                    LootPhase::SelectAlly => c(&[Back], "controls.skip_loot"),
+                    _ => c(&[Back], "controls.back"),
                 },
             ]),
             Phase::Dragon(DragonPhase::SelectAlly) => {
-                controls.append(&mut vec!["Space: Select", "Enter: Confirm"]);
+                controls.append(&mut vec![
+                    c(&[Select], "controls.select"),
+                    c(&[Confirm], "controls.confirm"),
+                ]);
             }
             Phase::Dragon(DragonPhase::Confirm) => {
-                controls.append(&mut vec!["Enter: Confirm", "Esc: Back"]);
+                controls.append(&mut vec![
+                    c(&[Confirm], "controls.confirm"),
+                    c(&[Back], "controls.back"),
+                ]);
+            }
+            Phase::Start if self.daily.is_some() => {
+                controls = vec![
+                    c(&[Prev, Next], "controls.hero"),
+                    c(&[Confirm], "controls.start"),
+                ];
+            }
+            Phase::Start if self.setup_hero().delves() == 0 => {
+                controls = vec![
+                    c(&[Prev, Next], "controls.hero"),
+                    c(&[Up, Down], "controls.mode"),
+                    c(&[Select], "controls.add_player"),
+                    c(&[Confirm], "controls.start"),
+                ];
+            }
+            Phase::Start => {
+                controls = vec![
+                    c(&[Prev, Next], "controls.hero"),
+                    c(&[Select], "controls.add_player"),
+                    c(&[Confirm], "controls.continue"),
+                ];
             }
-            Phase::EmptyDungeon => controls = vec!["Enter: Continue"],
+            Phase::Scoreboard => controls = vec![c(&[Confirm], "controls.next_turn")],
+            Phase::EmptyDungeon => controls = vec![c(&[Confirm], "controls.continue")],
             Phase::Regroup(RegroupPhase::Continue) => {
-                controls = vec!["↓: End delve", "Enter: Confirm"];
+                controls = vec![
+                    c(&[Down], "controls.end_delve"),
+                    c(&[Confirm], "controls.confirm"),
+                ];
+            }
+            Phase::Regroup(RegroupPhase::End) => {
+                controls = vec![
+                    c(&[Up], "controls.keep_going"),
+                    c(&[Confirm], "controls.confirm"),
+                ];
             }
-            Phase::Regroup(RegroupPhase::End) => controls = vec!["↑: Keep going", "Enter: Confirm"],
             _ => controls = Vec::new(),
         }
-        controls.push("Q: Exit");
+
+        controls
+    }
+
+    fn render_controls<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+        use Control::{Confirm, Leaderboard, Quit};
+        let c = |controls: &[Control], key: &str| self.control(controls, key);
+
+        let mut controls = self.phase_controls();
+        if self.is_rolling() {
+            controls = vec![c(&[Confirm], "controls.skip")];
+        } else if self.selected_row().is_some() {
+            controls.insert(
+                2,
+                format!("0-9: {}", self.language.text("controls.pick_die")),
+            );
+        }
+        if self.history.is_some() {
+            controls.push(c(&[Leaderboard], "controls.leaderboard"));
+        }
+        controls.push(c(&[Control::Help], "controls.help"));
+        controls.push(c(&[Quit], "controls.exit"));
 
         render_list(
             f,
             Block::default().borders(Borders::ALL),
             area,
-            &controls,
+            &controls.iter().map(String::as_str).collect::<Vec<_>>(),
+            2,
+        );
+    }
+
+    /// The rules the game is played with, as shown on the setup screen
+    fn rules_lines(&self) -> Vec<Spans<'static>> {
+        vec![
+            Spans::from(
+                self.language
+                    .format("setup.ruleset", &[("name", self.rules.name.clone())]),
+            ),
+            Spans::from(""),
+            Spans::from(self.language.format(
+                "setup.max_party_size",
+                &[("size", self.rules.max_party_size.to_string())],
+            )),
+            Spans::from(self.language.format(
+                "setup.max_dungeon_size",
+                &[("size", self.rules.max_dungeon_size.to_string())],
+            )),
+            describe(
+                self.language,
+                self.theme,
+                "setup.dragon_threshold",
+                &[("threshold", self.rules.dragon_threshold.to_string())],
+            ),
+            Spans::from(self.language.format(
+                "setup.xp_per_level",
+                &[("xp", self.rules.xp_per_level.to_string())],
+            )),
+        ]
+    }
+
+    fn render_setup<B: Backend>(&self, f: &mut Frame<B>, area: Rect, log: Option<&[String]>) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Ratio(3, 4), Constraint::Ratio(1, 4)])
+            .split(area);
+
+        let rules_area = render_block(
+            f,
+            Block::default()
+                .title(format!(" {} ", self.language.text("setup.title")))
+                .borders(Borders::ALL),
+            layout[0],
+        );
+
+        let hero = self.setup_hero();
+        let locked = hero.delves() > 0;
+        let mode_style = |mode: Mode| {
+            if mode == hero.mode() {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else if locked {
+                Style::default().add_modifier(Modifier::DIM)
+            } else {
+                Style::default()
+            }
+        };
+
+        let mut lines = vec![
+            Spans::from(Span::styled("Dungeon Roll", *TITLE_STYLE)),
+            Spans::from(""),
+        ];
+        if let Some(daily) = &self.daily {
+            lines.push(Spans::from(self.language.format(
+                "setup.daily",
+                &[
+                    ("date", daily.date.clone()),
+                    ("seed", daily.seed.to_string()),
+                ],
+            )));
+            lines.push(Spans::from(""));
+        }
+        lines.append(&mut vec![
+            Spans::from(format!(
+                "{} {} {}",
+                self.keymap.label(Control::Prev),
+                hero.title(self.language),
+                self.keymap.label(Control::Next)
+            )),
+            Spans::from(self.language.format(
+                "setup.stats",
+                &[
+                    ("level", hero.level().name(self.language).into_owned()),
+                    ("xp", hero.xp().to_string()),
+                    ("delves", hero.delves().to_string()),
+                    ("depth", hero.best_depth().to_string()),
+                ],
+            )),
+            Spans::from(""),
+            Spans::from(Span::styled(
+                Mode::Campaign.name(self.language),
+                mode_style(Mode::Campaign),
+            )),
+            Spans::from(Span::styled(
+                Mode::Classic.name(self.language),
+                mode_style(Mode::Classic),
+            )),
+            Spans::from(""),
+            Spans::from(match hero.mode() {
+                Mode::Campaign => self.language.text("setup.campaign").into_owned(),
+                Mode::Classic => self.language.format(
+                    "setup.classic",
+                    &[
+                        (
+                            "size",
+                            party_size(Mode::Classic, 0, &self.rules).to_string(),
+                        ),
+                        ("delves", CLASSIC_DELVES.to_string()),
+                    ],
+                ),
+            }),
+            Spans::from(""),
+        ]);
+        lines.append(&mut self.rules_lines());
+
+        if !self.players.is_empty() {
+            lines.push(Spans::from(""));
+            let players = self
+                .players
+                .iter()
+                .map(|p| self.roster[p.hero].name())
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(Spans::from(
+                self.language
+                    .format("setup.players", &[("players", players)]),
+            ));
+        }
+
+        let height = lines.len().try_into().unwrap_or(0);
+
+        f.render_widget(
+            Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+            vertical_center(rules_area, height),
+        );
+
+        self.render_footer(f, layout[1], log);
+    }
+
+    fn scoreboard(&self) -> Vec<Spans<'static>> {
+        self.players
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let hero = &self.roster[p.hero];
+                let line = self.language.format(
+                    "scoreboard.line",
+                    &[
+                        ("player", (i + 1).to_string()),
+                        ("hero", hero.title(self.language)),
+                        ("xp", hero.xp().to_string()),
+                        ("delves", hero.delves().to_string()),
+                        ("depth", hero.best_depth().to_string()),
+                    ],
+                );
+
+                if i == self.player {
+                    Spans::from(Span::styled(
+                        line,
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Spans::from(line)
+                }
+            })
+            .collect()
+    }
+
+    fn render_scoreboard<B: Backend>(&self, f: &mut Frame<B>, area: Rect, log: Option<&[String]>) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Ratio(3, 4), Constraint::Ratio(1, 4)])
+            .split(area);
+
+        let scores_area = render_block(
+            f,
+            Block::default()
+                .title(format!(" {} ", self.language.text("scoreboard.title")))
+                .borders(Borders::ALL),
+            layout[0],
+        );
+
+        let mut lines = vec![
+            Spans::from(Span::styled(
+                self.language.format(
+                    "scoreboard.over",
+                    &[("hero", self.hero().name().to_string())],
+                ),
+                *TITLE_STYLE,
+            )),
+            Spans::from(""),
+        ];
+        lines.append(&mut self.scoreboard());
+        lines.push(Spans::from(""));
+        lines.push(Spans::from(match self.next_player() {
+            Some(next) => self.language.format(
+                "scoreboard.pass",
+                &[(
+                    "hero",
+                    self.roster[self.players[next].hero].name().to_string(),
+                )],
+            ),
+            None => self.language.text("scoreboard.last").into_owned(),
+        }));
+        let height = lines.len().try_into().unwrap_or(0);
+
+        f.render_widget(
+            Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+            vertical_center(scores_area, height),
+        );
+
+        self.render_footer(f, layout[1], log);
+    }
+
+    fn leaderboard_hint(&self) -> Vec<Spans<'static>> {
+        if self.history.is_none() {
+            return Vec::new();
+        }
+
+        vec![
+            Spans::from(""),
+            Spans::from(Span::styled(
+                self.control(&[Control::Leaderboard], "controls.leaderboard"),
+                Style::default().add_modifier(Modifier::DIM),
+            )),
+        ]
+    }
+
+    /// Page `page` of the help, written from the same dice, treasure and
+    /// rules the game is played with
+    pub(super) fn help_page(&self, page: usize) -> Text<'static> {
+        let lines = match page {
+            0 => help_allies(self.theme, self.language),
+            1 => help_monsters(&self.rules, self.theme, self.language),
+            2 => {
+                let mut lines = Vec::new();
+                lines.push(Spans::from(
+                    self.language
+                        .format("help.treasure", &[("count", TREASURE.len().to_string())]),
+                ));
+                lines.push(Spans::from(""));
+                for treasure in &Treasure::ALL {
+                    let count = TREASURE.iter().filter(|t| *t == treasure).count();
+                    lines.push(Spans::from(self.language.format(
+                        "help.treasure_count",
+                        &[
+                            ("count", count.to_string()),
+                            ("treasure", treasure.name(self.language).into_owned()),
+                        ],
+                    )));
+                }
+                lines
+            }
+            3 => {
+                let mut lines = Vec::new();
+                lines.push(Spans::from(self.language.format(
+                    "help.heroes",
+                    &[(
+                        "heroes",
+                        Type::ALL.map(|t| t.name(self.language)).join(", "),
+                    )],
+                )));
+                lines.push(Spans::from(""));
+                lines.push(Spans::from(self.language.format(
+                    "help.campaign",
+                    &[
+                        ("mode", Mode::Campaign.name(self.language).into_owned()),
+                        ("size", self.rules.max_party_size.to_string()),
+                    ],
+                )));
+                let first = party_size(Mode::Campaign, 0, &self.rules);
+                for size in first..=self.rules.max_party_size {
+                    let xp = campaign_xp(size, &self.rules);
+                    lines.push(Spans::from(self.language.format(
+                        "help.campaign_size",
+                        &[("xp", xp.to_string()), ("size", size.to_string())],
+                    )));
+                }
+                lines.push(Spans::from(""));
+                lines.push(Spans::from(self.language.format(
+                    "help.classic",
+                    &[
+                        ("mode", Mode::Classic.name(self.language).into_owned()),
+                        (
+                            "size",
+                            party_size(Mode::Classic, 0, &self.rules).to_string(),
+                        ),
+                        ("delves", CLASSIC_DELVES.to_string()),
+                    ],
+                )));
+                lines
+            }
+            4 => {
+                let mut lines = Vec::new();
+                for phase in [
+                    Phase::Monster(MonsterPhase::SelectAlly),
+                    Phase::Loot(LootPhase::SelectAlly),
+                    Phase::Dragon(DragonPhase::SelectAlly),
+                    Phase::EmptyDungeon,
+                    Phase::Regroup(RegroupPhase::Continue),
+                    Phase::Regroup(RegroupPhase::End),
+                ] {
+                    lines.extend(phase_info(&phase, &self.rules, self.theme, self.language).lines);
+                    lines.push(Spans::from(""));
+                }
+                lines
+            }
+            _ => vec![
+                Spans::from(self.language.format(
+                    "help.level_xp",
+                    &[("xp", self.rules.xp_per_level.to_string())],
+                )),
+                Spans::from(""),
+                Spans::from(self.language.text("help.banking").into_owned()),
+                Spans::from(""),
+                Spans::from(self.language.format(
+                    "help.dungeon_dice",
+                    &[("size", self.rules.max_dungeon_size.to_string())],
+                )),
+                Spans::from(""),
+                Spans::from(self.language.text("help.victory").into_owned()),
+            ],
+        };
+
+        Text::from(lines)
+    }
+
+    fn render_help<B: Backend>(&self, f: &mut Frame<B>, area: Rect, help: &Help) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Ratio(3, 4), Constraint::Ratio(1, 4)])
+            .split(area);
+
+        let title = format!(
+            " {} ",
+            self.language.format(
+                "help.title",
+                &[
+                    ("page", (help.page + 1).to_string()),
+                    ("pages", PAGES.len().to_string()),
+                    ("name", self.language.text(PAGES[help.page]).into_owned()),
+                ],
+            )
+        );
+        let block = Block::default().title(title).borders(Borders::ALL);
+        let inner = render_block(f, block, layout[0]);
+        f.render_widget(
+            Paragraph::new(self.help_page(help.page))
+                .wrap(Wrap { trim: false })
+                .scroll((help.scroll, 0)),
+            Rect::new(
+                inner.x + 1,
+                inner.y,
+                inner.width.saturating_sub(2),
+                inner.height,
+            ),
+        );
+
+        let controls = [
+            self.control(&[Control::Prev, Control::Next], "controls.page"),
+            self.control(&[Control::Up, Control::Down], "controls.scroll"),
+            self.control(&[Control::Help], "controls.close"),
+        ];
+        render_list(
+            f,
+            Block::default().borders(Borders::ALL),
+            layout[1],
+            &controls.iter().map(String::as_str).collect::<Vec<_>>(),
+            2,
+        );
+    }
+
+    fn render_leaderboard<B: Backend>(&self, f: &mut Frame<B>, area: Rect, board: &Leaderboard) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Ratio(3, 4), Constraint::Ratio(1, 4)])
+            .split(area);
+
+        let heroes = board.filter.map_or_else(
+            || self.language.text("leaderboard.all_heroes").into_owned(),
+            |t| t.name(self.language).into_owned(),
+        );
+        let title = format!(
+            " {} ",
+            self.language.format(
+                "leaderboard.title",
+                &[
+                    ("heroes", heroes),
+                    ("sort", board.sort.name(self.language).into_owned()),
+                ],
+            )
+        );
+        let runs = self
+            .history
+            .as_ref()
+            .map(|h| h.leaderboard(board.sort, board.filter))
+            .unwrap_or_default();
+
+        let header = TableRow::new(
+            [
+                "leaderboard.header.rank",
+                "leaderboard.header.hero",
+                "leaderboard.header.xp",
+                "leaderboard.header.delve",
+                "leaderboard.header.level",
+                "leaderboard.header.treasure",
+                "leaderboard.header.ending",
+                "leaderboard.header.seed",
+            ]
+            .iter()
+            .map(|h| Cell::from(self.language.text(h)).style(*TITLE_STYLE)),
+        );
+        let rows = runs.iter().enumerate().map(|(i, run)| {
+            TableRow::new(vec![
+                Cell::from((i + 1).to_string()),
+                Cell::from(run.hero_type.title(&run.hero, self.language)),
+                Cell::from(run.xp.to_string()),
+                Cell::from(run.delve.to_string()),
+                Cell::from(run.level.to_string()),
+                Cell::from(run.treasure.to_string()),
+                Cell::from(run.ending.describe(self.language)),
+                Cell::from(run.seed.to_string()),
+            ])
+        });
+
+        f.render_widget(
+            Table::new(rows)
+                .header(header)
+                .block(Block::default().title(title).borders(Borders::ALL))
+                .column_spacing(2)
+                .widths(&[
+                    Constraint::Length(3),
+                    Constraint::Percentage(30),
+                    Constraint::Length(4),
+                    Constraint::Length(5),
+                    Constraint::Length(5),
+                    Constraint::Length(8),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(20),
+                ]),
+            layout[0],
+        );
+
+        let controls = [
+            self.control(&[Control::Prev, Control::Next], "controls.hero"),
+            self.control(&[Control::Up, Control::Down], "controls.sort"),
+            self.control(&[Control::Leaderboard], "controls.close"),
+        ];
+        render_list(
+            f,
+            Block::default().borders(Borders::ALL),
+            layout[1],
+            &controls.iter().map(String::as_str).collect::<Vec<_>>(),
             2,
         );
     }
 
-    fn render_array<B: Backend, T: Render>(
+    fn render_array<B: Backend, T: Render + Dice>(
         &self,
         f: &mut Frame<B>,
         area: Rect,
@@ -370,7 +1023,7 @@ impl<R: Rng> Game<R> {
             area,
         );
 
-        let row = Layout::default()
+        let cols = Layout::default()
             .direction(Direction::Horizontal)
             .constraints(
                 repeat(Constraint::Ratio(1, data.len().try_into().unwrap_or(1)))
@@ -379,16 +1032,89 @@ impl<R: Rng> Game<R> {
             )
             .split(array_area);
 
-        row.iter().zip(data).enumerate().for_each(|(i, (col, t))| {
+        // every die is drawn the same size, a row is left for the labels
+        let width = cols.iter().map(|c| c.width).min().unwrap_or(0);
+        let height = array_area.height.saturating_sub(1);
+
+        cols.iter().zip(data).enumerate().for_each(|(i, (col, t))| {
+            self.add_hitbox(*col, Hitbox::Die(row, i));
+
             let style = style_fn(self, i);
-            let mut sprite = t.render();
+            let face = self.rolling_face::<T>(row, i);
+            let die = face.as_ref().unwrap_or(t);
+            let mut sprite = die.sprite(self.theme, self.language, width, height);
             sprite.patch_style(style);
 
-            let r = vertical_center(*col, 1);
+            let height = sprite.height().try_into().unwrap_or(1);
+            let r = vertical_center(*col, height);
             f.render_widget(Paragraph::new(sprite).alignment(Alignment::Center), r);
+
+            // the number key that picks the die
+            if i < 10 && r.y > col.y {
+                let label = Rect {
+                    y: r.y - 1,
+                    height: 1,
+                    ..r
+                };
+                f.render_widget(
+                    Paragraph::new(((i + 1) % 10).to_string())
+                        .style(Style::default().add_modifier(Modifier::DIM))
+                        .alignment(Alignment::Center),
+                    label,
+                );
+            }
         });
     }
 
+    fn render_regroup<B: Backend>(&self, f: &mut Frame<B>, area: Rect, rp: &RegroupPhase) {
+        let style_top = Style::default();
+        let style_bottom = Style::default();
+        let (style_top, style_bottom) = match rp {
+            RegroupPhase::Continue => (style_top.add_modifier(Modifier::REVERSED), style_bottom),
+            RegroupPhase::End => (style_top, style_bottom.add_modifier(Modifier::REVERSED)),
+            _ => (style_top, style_bottom),
+        };
+
+        let question_area = render_block(
+            f,
+            Block::default()
+                .title(format!(
+                    " {} ",
+                    self.language.text("phase.regroup.question")
+                ))
+                .border_type(BorderType::Thick)
+                .borders(Borders::ALL),
+            area,
+        );
+
+        let subchunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Ratio(2, 6),
+                    Constraint::Ratio(1, 6),
+                    Constraint::Ratio(1, 6),
+                    Constraint::Ratio(2, 6),
+                ]
+                .as_ref(),
+            )
+            .split(question_area);
+        f.render_widget(
+            Paragraph::new(self.language.text("phase.regroup.legend"))
+                .style(style_top)
+                .alignment(Alignment::Center),
+            subchunks[1],
+        );
+        f.render_widget(
+            Paragraph::new(self.language.text("phase.regroup.tavern"))
+                .style(style_bottom)
+                .alignment(Alignment::Center),
+            subchunks[2],
+        );
+        self.add_hitbox(subchunks[1], Hitbox::Continue);
+        self.add_hitbox(subchunks[2], Hitbox::End);
+    }
+
     fn render_playfield<B: Backend, S: Styler>(&self, f: &mut Frame<B>, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -406,58 +1132,24 @@ impl<R: Rng> Game<R> {
             .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
             .split(chunks[2]);
 
+        let dungeon_title = format!(
+            " {} ",
+            self.language.format(
+                "playfield.dungeon",
+                &[
+                    ("delve", self.delve.to_string()),
+                    ("level", self.level.to_string()),
+                ],
+            )
+        );
         match self.phase {
-            Phase::Dragon(_) => render_dragon(f, chunks[0]),
-            Phase::Regroup(ref rp) => {
-                let style_top = Style::default();
-                let style_bottom = Style::default();
-                let (style_top, style_bottom) = match rp {
-                    RegroupPhase::Continue => {
-                        (style_top.add_modifier(Modifier::REVERSED), style_bottom)
-                    }
-                    RegroupPhase::End => (style_top, style_bottom.add_modifier(Modifier::REVERSED)),
-                    _ => (style_top, style_bottom),
-                };
-
-                let question_area = render_block(
-                    f,
-                    Block::default()
-                        .title(" What will you do? ")
-                        .border_type(BorderType::Thick)
-                        .borders(Borders::ALL),
-                    chunks[0],
-                );
-
-                let subchunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints(
-                        [
-                            Constraint::Ratio(2, 6),
-                            Constraint::Ratio(1, 6),
-                            Constraint::Ratio(1, 6),
-                            Constraint::Ratio(2, 6),
-                        ]
-                        .as_ref(),
-                    )
-                    .split(question_area);
-                f.render_widget(
-                    Paragraph::new("Stuff of Legend")
-                        .style(style_top)
-                        .alignment(Alignment::Center),
-                    subchunks[1],
-                );
-                f.render_widget(
-                    Paragraph::new("Retire to the Tavern")
-                        .style(style_bottom)
-                        .alignment(Alignment::Center),
-                    subchunks[2],
-                );
-            }
+            Phase::Dragon(_) => render_dragon(f, chunks[0], self.language),
+            Phase::Regroup(ref rp) => self.render_regroup(f, chunks[0], rp),
             Phase::EmptyDungeon => {
                 render_block(
                     f,
                     Block::default()
-                        .title(format!(" Dungeon #{} lvl. {} ", self.delve, self.level))
+                        .title(dungeon_title.as_str())
                         .borders(Borders::ALL),
                     chunks[0],
                 );
@@ -466,7 +1158,7 @@ impl<R: Rng> Game<R> {
                 f,
                 chunks[0],
                 Row::Dungeon,
-                &format!(" Dungeon #{} lvl. {} ", self.delve, self.level),
+                &dungeon_title,
                 &*self.dungeon,
                 S::dungeon_style,
             ),
@@ -475,7 +1167,7 @@ impl<R: Rng> Game<R> {
             f,
             chunks[1],
             Row::Party,
-            " Party ",
+            &format!(" {} ", self.language.text("playfield.party")),
             &*self.party,
             S::party_style,
         );
@@ -483,106 +1175,315 @@ impl<R: Rng> Game<R> {
             f,
             subchunks[0],
             Row::Graveyard,
-            " Graveyard ",
+            &format!(" {} ", self.language.text("playfield.graveyard")),
             &self.graveyard,
             S::graveyard_style,
         );
 
+        let title = if self.players.len() > 1 {
+            self.language.format(
+                "playfield.player_inventory",
+                &[
+                    ("player", (self.player + 1).to_string()),
+                    ("hero", self.hero().name().to_string()),
+                ],
+            )
+        } else {
+            self.language.text("playfield.inventory").into_owned()
+        };
+
         render_list(
             f,
-            Block::default().title(" Inventory ").borders(Borders::ALL),
+            Block::default()
+                .title(format!(" {title} "))
+                .borders(Borders::ALL),
             subchunks[1],
             &[
-                &format!("XP: {}", self.run_xp),
-                &format!("Loot: {}", self.inventory.len()),
-                &format!("Total XP: {}", self.hero.xp()),
-                &format!("Party size: {}", self.party_size),
+                &self
+                    .language
+                    .format("playfield.xp", &[("xp", self.run_xp.to_string())]),
+                &self.language.format(
+                    "playfield.loot",
+                    &[("loot", self.inventory().len().to_string())],
+                ),
+                &self.language.format(
+                    "playfield.total_xp",
+                    &[("xp", self.hero().xp().to_string())],
+                ),
+                &self.language.format(
+                    "playfield.party_size",
+                    &[("size", self.party_size.to_string())],
+                ),
             ],
             2,
         );
     }
 
+    fn render_footer<B: Backend>(&self, f: &mut Frame<B>, area: Rect, log: Option<&[String]>) {
+        match log {
+            Some(log) => render_log(f, area, log, self.language),
+            None => self.render_controls(f, area),
+        }
+    }
+
     pub fn render<'a, B: Backend>(
         &self,
         terminal: &'a mut Terminal<B>,
     ) -> Result<CompletedFrame<'a>, io::Error> {
         terminal.draw(|f| {
-            if self.phase == Phase::Setup {
-                return;
-            }
+            self.draw(f, None);
+            self.render_tutorial(f);
+            self.render_toast(f);
+        })
+    }
 
-            let game = render_block(f, Block::default().borders(Borders::ALL), f.size());
+    /// Renders the game for someone who can't play, with the log of actions
+    /// in place of the controls
+    pub fn render_spectator<'a, B: Backend>(
+        &self,
+        terminal: &'a mut Terminal<B>,
+        log: &[String],
+    ) -> Result<CompletedFrame<'a>, io::Error> {
+        terminal.draw(|f| {
+            self.draw(f, Some(log));
+            self.render_tutorial(f);
+            self.render_toast(f);
+        })
+    }
 
-            if self.phase == Phase::Victory {
-                f.render_widget(
-                    Paragraph::new(Text::from(vec![
-                        Spans::from(Span::styled(
-                            "Victory!",
-                            Style::default().add_modifier(Modifier::RAPID_BLINK | Modifier::BOLD),
-                        )),
-                        Spans::from(""),
-                        Spans::from(Span::styled(
-                            "Your prize is under Miky's bed",
-                            Style::default()
-                                .fg(Color::DarkGray)
-                                .add_modifier(Modifier::DIM),
-                        )),
-                    ]))
-                    .alignment(Alignment::Center),
-                    vertical_center(game, 3),
-                );
-                return;
+    /// Shows what the tutorial expects next at the bottom of the screen,
+    /// over the controls
+    fn render_tutorial<B: Backend>(&self, f: &mut Frame<B>) {
+        let Some(prompt) = self.tutorial_prompt() else {
+            return;
+        };
+        if self.help.is_some() || self.leaderboard.is_some() || too_small(f.size()) {
+            return;
+        }
+
+        let size = f.size();
+        let width = size.width.saturating_sub(4).min(72);
+        let lines = wrapped_lines(&prompt, usize::from(width.saturating_sub(2)));
+        let height = u16::try_from(lines + 2)
+            .unwrap_or(u16::MAX)
+            .min(size.height);
+        let area = Rect::new(
+            (size.width - width) / 2,
+            size.height.saturating_sub(height + 1),
+            width,
+            height,
+        );
+
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Paragraph::new(prompt).wrap(Wrap { trim: true }).block(
+                Block::default()
+                    .title(format!(" {} ", self.language.text("tutorial.title")))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            ),
+            area,
+        );
+    }
+
+    /// Announces the latest achievement in the top right corner, over
+    /// whatever is on screen
+    fn render_toast<B: Backend>(&self, f: &mut Frame<B>) {
+        let Some(achievement) = self.toast() else {
+            return;
+        };
+        if too_small(f.size()) {
+            return;
+        }
+
+        let text = Text::from(vec![
+            Spans::from(Span::styled(achievement.name(self.language), *TITLE_STYLE)),
+            Spans::from(achievement.describe(self.language).into_owned()),
+        ]);
+        let size = f.size();
+        let width = u16::try_from(text.width())
+            .unwrap_or(u16::MAX)
+            .saturating_add(4)
+            .min(size.width);
+        let area = Rect::new(size.width - width, 0, width, 4.min(size.height));
+
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Paragraph::new(text).alignment(Alignment::Center).block(
+                Block::default()
+                    .title(format!(" {} ", self.language.text("achievement.unlocked")))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            ),
+            area,
+        );
+    }
+
+    /// The end of a delve that slew the dragon
+    fn victory_lines(&self) -> Vec<Spans<'static>> {
+        let mut lines = vec![
+            Spans::from(Span::styled(
+                self.language.text("victory.title"),
+                Style::default().add_modifier(Modifier::RAPID_BLINK | Modifier::BOLD),
+            )),
+            Spans::from(""),
+            Spans::from(Span::styled(
+                self.language.text("victory.prize"),
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::DIM),
+            )),
+        ];
+        if self.players.len() > 1 {
+            lines.push(Spans::from(""));
+            lines.append(&mut self.scoreboard());
+        }
+        lines.push(Spans::from(""));
+        lines.push(Spans::from(Span::styled(
+            self.control(&[Control::Confirm], "controls.continue"),
+            Style::default().add_modifier(Modifier::DIM),
+        )));
+        lines.append(&mut self.leaderboard_hint());
+        lines
+    }
+
+    /// The end of the game
+    fn game_over_lines(&self) -> Vec<Spans<'static>> {
+        let mut lines = vec![
+            Spans::from(Span::styled(
+                self.language.text("game_over.title"),
+                *TITLE_STYLE,
+            )),
+            Spans::from(""),
+        ];
+        if let Some((goal, status)) = self.goal() {
+            lines.push(Spans::from(goal.describe(self.language)));
+            let status = if status == GoalStatus::Solved {
+                "game_over.solved"
+            } else {
+                "game_over.failed"
+            };
+            lines.push(Spans::from(self.language.text(status).into_owned()));
+        } else {
+            if let Some(daily) = &self.daily {
+                lines.push(Spans::from(
+                    self.language
+                        .format("game_over.daily", &[("date", daily.date.clone())]),
+                ));
             }
+            lines.append(&mut self.scoreboard());
+        }
+        lines.append(&mut self.leaderboard_hint());
+        lines
+    }
+
+    fn draw<B: Backend>(&self, f: &mut Frame<B>, log: Option<&[String]>) {
+        self.clear_hitboxes();
+        if self.phase == Phase::Setup {
+            return;
+        }
+
+        if too_small(f.size()) {
+            render_too_small(f, self.language);
+            return;
+        }
 
-            let layout = Layout::default()
-                .direction(Direction::Vertical)
-                .margin(1)
-                .constraints([Constraint::Ratio(3, 4), Constraint::Ratio(1, 4)])
-                .split(game);
+        let game = render_block(f, Block::default().borders(Borders::ALL), f.size());
 
+        if let Some(help) = &self.help {
+            self.render_help(f, game, help);
+            return;
+        }
+
+        if let Some(board) = &self.leaderboard {
+            self.render_leaderboard(f, game, board);
+            return;
+        }
+
+        if self.phase == Phase::Start {
+            self.render_setup(f, game, log);
+            return;
+        }
+
+        if matches!(self.phase, Phase::Victory | Phase::GameOver) {
+            let lines = if self.phase == Phase::Victory {
+                self.victory_lines()
+            } else {
+                self.game_over_lines()
+            };
+            let height = lines.len().try_into().unwrap_or(0);
+
+            f.render_widget(
+                Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+                vertical_center(game, height),
+            );
+            return;
+        }
+
+        if self.phase == Phase::Scoreboard {
+            self.render_scoreboard(f, game, log);
+            return;
+        }
+
+        let compact = game.width < COMPACT_WIDTH || game.height < COMPACT_HEIGHT;
+        let margin = u16::from(!compact);
+        let footer = if compact {
+            // the controls, two rows of them
+            Constraint::Length(4)
+        } else {
+            Constraint::Ratio(1, 4)
+        };
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(margin)
+            .constraints([Constraint::Min(0), footer])
+            .split(game);
+
+        // narrow terminals only fit the dice
+        let playfield = if game.width < COMPACT_WIDTH {
+            layout[0]
+        } else {
             let sublayout = Layout::default()
                 .direction(Direction::Horizontal)
-                .margin(1)
+                .margin(margin)
                 .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
                 .split(layout[0]);
+            self.render_info(f, sublayout[1]);
+            sublayout[0]
+        };
 
-            let info = sublayout[1];
-            self.render_info(f, info);
-
-            let playfield = sublayout[0];
-            match &self.phase {
-                Phase::Monster(_) => self.render_playfield::<_, MPStyler>(f, playfield),
-                Phase::Loot(_) => self.render_playfield::<_, LPStyler>(f, playfield),
-                Phase::Dragon(_) => self.render_playfield::<_, DPStyler>(f, playfield),
-                Phase::Regroup(_) | Phase::EmptyDungeon => {
-                    self.render_playfield::<_, GenericStyler>(f, playfield)
-                }
-                _ => (),
-            };
+        match &self.phase {
+            Phase::Monster(_) => self.render_playfield::<_, MPStyler>(f, playfield),
+            Phase::Loot(_) => self.render_playfield::<_, LPStyler>(f, playfield),
+            Phase::Dragon(_) => self.render_playfield::<_, DPStyler>(f, playfield),
+            Phase::Regroup(_) | Phase::EmptyDungeon => {
+                self.render_playfield::<_, GenericStyler>(f, playfield)
+            }
+            _ => (),
+        };
 
-            let controls = layout[1];
-            self.render_controls(f, controls);
-        })
+        let controls = layout[1];
+        self.render_footer(f, controls, log);
     }
 }
 
 trait Styler {
-    fn dungeon_style<R: Rng>(_game: &Game<R>, _i: usize) -> Style {
+    fn dungeon_style<R: DiceSource>(_game: &Game<R>, _i: usize) -> Style {
         Style::default()
     }
 
-    fn party_style<R: Rng>(_game: &Game<R>, _i: usize) -> Style {
+    fn party_style<R: DiceSource>(_game: &Game<R>, _i: usize) -> Style {
         Style::default()
     }
 
-    fn graveyard_style<R: Rng>(_game: &Game<R>, _i: usize) -> Style {
+    fn graveyard_style<R: DiceSource>(_game: &Game<R>, _i: usize) -> Style {
         Style::default()
     }
 }
 
 struct MPStyler;
 impl Styler for MPStyler {
-    fn dungeon_style<R: Rng>(game: &Game<R>, i: usize) -> Style {
+    fn dungeon_style<R: DiceSource>(game: &Game<R>, i: usize) -> Style {
         let equal_monsters = indexes_of(&game.dungeon, game.current_monster());
         let is_selected = |i: usize| i == game.dungeon.cursor(DungeonCursor::Monster as usize);
         let is_affected =
@@ -590,56 +1491,44 @@ impl Styler for MPStyler {
         let is_reroll_selected =
             |i: usize| i == game.dungeon.cursor(DungeonCursor::Reroll as usize);
 
-        let style = Style::default();
         match game.phase {
             Phase::Monster(ref mp) => match mp {
-                MonsterPhase::SelectMonster if game.blink && is_selected(i) => {
-                    style.bg(Color::White)
-                }
+                MonsterPhase::SelectMonster if game.blink && is_selected(i) => game.theme.cursor(),
                 MonsterPhase::SelectReroll(Reroll::Monster)
                     if game.blink && is_reroll_selected(i) =>
                 {
-                    style.bg(Color::White)
+                    game.theme.cursor()
                 }
                 MonsterPhase::SelectMonster if !is_selected(i) && is_affected(i) => {
-                    style.bg(Color::DarkGray)
+                    game.theme.selected()
                 }
                 MonsterPhase::SelectReroll(_) if game.dungeon.is_selected(i) => {
-                    style.bg(Color::DarkGray)
+                    game.theme.selected()
                 }
                 MonsterPhase::ConfirmReroll if game.dungeon.is_selected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
+                    game.theme.confirmed()
                 }
-                MonsterPhase::ConfirmCombat if is_affected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
-                }
-                _ => style.bg(Color::Black),
+                MonsterPhase::ConfirmCombat if is_affected(i) => game.theme.confirmed(),
+                _ => game.theme.idle(),
             },
             _ => unreachable!(),
         }
     }
 
-    fn party_style<R: Rng>(game: &Game<R>, i: usize) -> Style {
+    fn party_style<R: DiceSource>(game: &Game<R>, i: usize) -> Style {
         let is_selected = |i: usize| i == game.party.cursor(PartyCursor::Ally as usize);
         let is_reroll_selected = |i: usize| i == game.party.cursor(PartyCursor::Reroll as usize);
 
-        let style = Style::default();
         match game.phase {
             Phase::Monster(ref mp) => match mp {
-                MonsterPhase::SelectAlly if game.blink && is_selected(i) => style.bg(Color::White),
+                MonsterPhase::SelectAlly if game.blink && is_selected(i) => game.theme.cursor(),
                 MonsterPhase::SelectReroll(Reroll::Ally) if game.blink && is_reroll_selected(i) => {
-                    style.bg(Color::White)
-                }
-                MonsterPhase::SelectReroll(_) if game.party.is_selected(i) => {
-                    style.bg(Color::DarkGray)
-                }
-                MonsterPhase::ConfirmReroll if game.party.is_selected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
+                    game.theme.cursor()
                 }
-                _ if mp != &MonsterPhase::SelectAlly && is_selected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
-                }
-                _ => style.bg(Color::Black),
+                MonsterPhase::SelectReroll(_) if game.party.is_selected(i) => game.theme.selected(),
+                MonsterPhase::ConfirmReroll if game.party.is_selected(i) => game.theme.confirmed(),
+                _ if mp != &MonsterPhase::SelectAlly && is_selected(i) => game.theme.confirmed(),
+                _ => game.theme.idle(),
             },
             _ => unreachable!(),
         }
@@ -648,7 +1537,7 @@ impl Styler for MPStyler {
 
 struct LPStyler;
 impl Styler for LPStyler {
-    fn dungeon_style<R: Rng>(game: &Game<R>, i: usize) -> Style {
+    fn dungeon_style<R: DiceSource>(game: &Game<R>, i: usize) -> Style {
         let cursor = game.dungeon.cursor(DungeonCursor::Monster as usize);
 
         let mut equal_monsters = indexes_of(&game.dungeon, game.current_monster());
@@ -661,60 +1550,47 @@ impl Styler for LPStyler {
         let is_affected =
             |i: usize| game.affects_all() && equal_monsters.contains(&i) || is_selected(i);
 
-        let style = Style::default();
         match game.phase {
             Phase::Loot(ref mp) => match mp {
-                LootPhase::SelectLoot if game.blink && is_selected(i) => style.bg(Color::White),
-                LootPhase::SelectLoot if is_affected(i) => style.bg(Color::DarkGray),
-                LootPhase::ConfirmLoot if is_affected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
-                }
-                LootPhase::SelectGraveyard if is_affected(i) => style.bg(Color::DarkGray),
-                LootPhase::ConfirmGraveyard if is_affected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
-                }
-                _ => style.bg(Color::Black),
+                LootPhase::SelectLoot if game.blink && is_selected(i) => game.theme.cursor(),
+                LootPhase::SelectLoot if is_affected(i) => game.theme.selected(),
+                LootPhase::ConfirmLoot if is_affected(i) => game.theme.confirmed(),
+                LootPhase::SelectGraveyard if is_affected(i) => game.theme.selected(),
+                LootPhase::ConfirmGraveyard if is_affected(i) => game.theme.confirmed(),
+                _ => game.theme.idle(),
             },
             _ => unreachable!(),
         }
     }
 
-    fn party_style<R: Rng>(game: &Game<R>, i: usize) -> Style {
+    fn party_style<R: DiceSource>(game: &Game<R>, i: usize) -> Style {
         let is_selected = |i: usize| i == game.party.cursor(PartyCursor::Ally as usize);
 
-        let style = Style::default();
         match game.phase {
             Phase::Loot(ref lp) => match lp {
-                LootPhase::SelectAlly if game.blink && is_selected(i) => style.bg(Color::White),
-                LootPhase::ConfirmLoot if is_selected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
-                }
-                LootPhase::SelectGraveyard if is_selected(i) => style.bg(Color::DarkGray),
-                LootPhase::ConfirmGraveyard if is_selected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
-                }
-                _ => style.bg(Color::Black),
+                LootPhase::SelectAlly if game.blink && is_selected(i) => game.theme.cursor(),
+                LootPhase::ConfirmLoot if is_selected(i) => game.theme.confirmed(),
+                LootPhase::SelectGraveyard if is_selected(i) => game.theme.selected(),
+                LootPhase::ConfirmGraveyard if is_selected(i) => game.theme.confirmed(),
+                _ => game.theme.idle(),
             },
             _ => unreachable!(),
         }
     }
 
-    fn graveyard_style<R: Rng>(game: &Game<R>, i: usize) -> Style {
+    fn graveyard_style<R: DiceSource>(game: &Game<R>, i: usize) -> Style {
         let is_selected = |i: usize| i == game.graveyard.cursor(0);
 
-        let style = Style::default();
         match game.phase {
             Phase::Loot(ref lp) => match lp {
-                LootPhase::SelectGraveyard if game.blink && is_selected(i) => {
-                    style.bg(Color::White)
-                }
+                LootPhase::SelectGraveyard if game.blink && is_selected(i) => game.theme.cursor(),
                 LootPhase::SelectGraveyard if game.graveyard.is_selected(i) => {
-                    style.bg(Color::DarkGray)
+                    game.theme.selected()
                 }
                 LootPhase::ConfirmGraveyard if game.graveyard.is_selected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
+                    game.theme.confirmed()
                 }
-                _ => style.bg(Color::Black),
+                _ => game.theme.idle(),
             },
             _ => unreachable!(),
         }
@@ -723,18 +1599,15 @@ impl Styler for LPStyler {
 
 struct DPStyler;
 impl Styler for DPStyler {
-    fn party_style<R: Rng>(game: &Game<R>, i: usize) -> Style {
+    fn party_style<R: DiceSource>(game: &Game<R>, i: usize) -> Style {
         let is_selected = |i: usize| i == game.party.cursor(PartyCursor::Ally as usize);
 
-        let style = Style::default();
         match game.phase {
             Phase::Dragon(ref dp) => match dp {
-                DragonPhase::SelectAlly if game.blink && is_selected(i) => style.bg(Color::White),
-                DragonPhase::SelectAlly if game.party.is_selected(i) => style.bg(Color::DarkGray),
-                DragonPhase::Confirm if game.party.is_selected(i) => {
-                    style.bg(Color::DarkGray).add_modifier(Modifier::DIM)
-                }
-                _ => style.bg(Color::Black),
+                DragonPhase::SelectAlly if game.blink && is_selected(i) => game.theme.cursor(),
+                DragonPhase::SelectAlly if game.party.is_selected(i) => game.theme.selected(),
+                DragonPhase::Confirm if game.party.is_selected(i) => game.theme.confirmed(),
+                _ => game.theme.idle(),
             },
             _ => unreachable!(),
         }
@@ -0,0 +1,78 @@
+use super::{DiceSource, DragonPhase, Game, LootPhase, MonsterPhase, Phase, Player, Roster, Rules};
+use crate::{
+    hero::Hero,
+    locale::Language,
+    scenario::{Goal, GoalStatus, Scenario, Start},
+};
+
+impl<R: DiceSource> Game<R> {
+    /// Starts a single-hero game from a hand-authored state, the roster is
+    /// never written back
+    pub fn with_scenario(rng: R, rules: Rules, scenario: Scenario) -> Self {
+        let hero = Hero::new(
+            scenario.hero.name(Language::English).into_owned(),
+            scenario.hero,
+        );
+        let mut game = Self::new(rng, Roster::from(vec![hero]), rules);
+
+        let mut player = Player::new(0);
+        for item in &scenario.inventory {
+            if let Some(i) = game.treasure.iter().position(|t| t == item) {
+                game.treasure.remove(i);
+            }
+        }
+        player.inventory = scenario.inventory;
+        game.players.push(player);
+
+        game.delve = scenario.delve;
+        game.level = scenario.level;
+        game.run_xp = (1..=scenario.level).sum::<u64>() * game.rules.xp_per_level;
+        game.party_size = scenario.party.len() as u64;
+        game.party.set_data(scenario.party);
+        game.dungeon.set_data(scenario.dungeon);
+        game.graveyard.set_data(scenario.graveyard);
+        game.goal = Some(scenario.goal);
+
+        game.phase = match scenario.phase {
+            Start::Monster => Phase::Monster(MonsterPhase::SelectAlly),
+            Start::Loot => Phase::Loot(LootPhase::SelectAlly),
+            Start::Dragon => Phase::Dragon(DragonPhase::SelectAlly),
+        };
+        while game.enter_phase_trigger() {}
+        game.check_goal();
+
+        game
+    }
+
+    pub fn goal(&self) -> Option<(Goal, GoalStatus)> {
+        self.goal.map(|goal| (goal, self.goal_status))
+    }
+
+    /// Ends the game as soon as the goal is either reached or out of reach
+    pub(super) fn check_goal(&mut self) {
+        let Some(goal) = self.goal else {
+            return;
+        };
+        if self.goal_status != GoalStatus::Pending {
+            return;
+        }
+
+        let over_budget = goal.max_allies().is_some_and(|max| self.allies_spent > max);
+        let delve_over = self.hero().delves() > 0;
+
+        self.goal_status = match goal {
+            _ if over_budget => GoalStatus::Failed,
+            Goal::ClearLevel { .. } if matches!(self.phase, Phase::Regroup(_) | Phase::Victory) => {
+                GoalStatus::Solved
+            }
+            Goal::SlayDragon { .. } if self.phase == Phase::Victory => GoalStatus::Solved,
+            Goal::ReachLevel { level } if self.level >= level && !delve_over => GoalStatus::Solved,
+            _ if delve_over => GoalStatus::Failed,
+            _ => GoalStatus::Pending,
+        };
+
+        if self.goal_status != GoalStatus::Pending {
+            self.phase = Phase::GameOver;
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use super::{Command, DiceSource, Game, Mode, Phase};
+use std::fmt::{Debug, Write};
+
+pub static HELP: &str = "\
+hero <i>                  pick hero <i> of the roster
+player                    add or remove the picked hero as a player
+mode campaign|classic     set the mode of the picked hero
+start                     start delving
+fight <ally> <monster>    defeat monsters with a companion
+reroll p<i>,.. d<i>,..    use a scroll to re-roll party and dungeon dice
+open <ally> <chest>       open chests with a companion
+quaff <ally> [g<i>,..]    quaff potions to revive allies from the graveyard
+skip                      skip the loot phase
+dragon <a>,<b>,<c>        fight the dragon with three companions
+continue                  delve deeper into the dungeon
+retire                    end the delve and keep the XP
+next                      move on from an empty dungeon, a defeat or the scoreboard
+state                     print the game state
+help                      print this help
+quit                      exit the game";
+
+fn parse_index(s: &str) -> Result<usize, String> {
+    s.parse().map_err(|_| format!("'{s}' is not an index"))
+}
+
+fn parse_list(s: &str, prefix: char) -> Result<Vec<usize>, String> {
+    s.split(',')
+        .filter(|t| !t.is_empty())
+        .map(|t| parse_index(t.strip_prefix(prefix).unwrap_or(t)))
+        .collect()
+}
+
+fn describe_row<T: Debug>(name: &str, row: &[T]) -> String {
+    let dice = row
+        .iter()
+        .enumerate()
+        .map(|(i, t)| format!("{i}:{t:?}"))
+        .collect::<Vec<_>>();
+    format!("{name}: {}\n", dice.join(" "))
+}
+
+impl<R: DiceSource> Game<R> {
+    /// Runs one line of the text protocol, see `HELP`
+    pub fn execute(&mut self, line: &str) -> Result<(), String> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let command = match words.as_slice() {
+            ["hero", i] => Command::Hero {
+                index: parse_index(i)?,
+            },
+            ["player"] => Command::Player,
+            ["mode", mode] => Command::Mode {
+                mode: match *mode {
+                    "campaign" => Mode::Campaign,
+                    "classic" => Mode::Classic,
+                    _ => return Err(format!("unknown mode '{mode}'")),
+                },
+            },
+            ["start"] => Command::Start,
+            ["fight", ally, monster] => Command::Fight {
+                ally: parse_index(ally)?,
+                monster: parse_index(monster)?,
+            },
+            ["reroll", lists @ ..] => {
+                let (mut party, mut dungeon) = (Vec::new(), Vec::new());
+                for list in lists {
+                    match list.chars().next() {
+                        Some('p') => party.append(&mut parse_list(list, 'p')?),
+                        Some('d') => dungeon.append(&mut parse_list(list, 'd')?),
+                        _ => return Err(format!("'{list}' is neither p<i> nor d<i>")),
+                    }
+                }
+                Command::Reroll { party, dungeon }
+            }
+            ["open", ally, chest] => Command::Open {
+                ally: parse_index(ally)?,
+                chest: parse_index(chest)?,
+            },
+            ["quaff", ally] => Command::Quaff {
+                ally: parse_index(ally)?,
+                graves: None,
+            },
+            ["quaff", ally, graves] => Command::Quaff {
+                ally: parse_index(ally)?,
+                graves: Some(parse_list(graves, 'g')?),
+            },
+            ["skip"] => Command::Skip,
+            ["dragon", allies] => Command::Dragon {
+                allies: parse_list(allies, 'p')?,
+            },
+            ["continue"] => Command::Continue,
+            ["retire"] => Command::Retire,
+            ["next"] => Command::Next,
+            [] | ["state"] => return Ok(()),
+            [command, ..] => return Err(format!("unknown command '{command}'")),
+        };
+
+        self.perform(&command)
+    }
+
+    /// Plain text description of everything a player can see
+    pub fn describe(&self) -> String {
+        let mut s = format!("phase: {:?}\n", self.phase);
+
+        if self.phase == Phase::Start {
+            for (i, hero) in self.roster.iter().enumerate() {
+                let _ = writeln!(
+                    s,
+                    "{}hero {i}: {} ({:?}, {} XP, {} delves, {})",
+                    if i == self.selected_hero { "> " } else { "" },
+                    hero.title(self.language),
+                    hero.level(),
+                    hero.xp(),
+                    hero.delves(),
+                    hero.mode().name(self.language),
+                );
+            }
+            return s;
+        }
+
+        if let Some((goal, status)) = self.goal() {
+            let _ = writeln!(s, "goal: {} ({status:?})", goal.describe(self.language));
+        }
+        let _ = writeln!(
+            s,
+            "player: {} ({}/{})",
+            self.hero().name(),
+            self.player + 1,
+            self.players.len()
+        );
+        let _ = writeln!(
+            s,
+            "delve: {} level: {} xp: {} total xp: {}",
+            self.delve,
+            self.level,
+            self.run_xp,
+            self.hero().xp()
+        );
+        s.push_str(&describe_row("dungeon", &self.dungeon));
+        s.push_str(&describe_row("party", &self.party));
+        s.push_str(&describe_row("graveyard", &self.graveyard));
+        s.push_str(&describe_row("inventory", self.inventory()));
+
+        s
+    }
+}
@@ -0,0 +1,443 @@
+use super::{
+    Action, Ally, DiceSource, DungeonCursor, Game, Mode, Monster, PartyCursor, Roster, Rules,
+};
+use crate::{
+    dice_source::ScriptedDice,
+    hero::{Hero, Type},
+    keymap::Control,
+};
+
+/// What has to be under the cursor before a step can be finished
+enum Target {
+    Any,
+    Ally(Ally),
+    Monster(Monster),
+    Reroll(Monster),
+    Grave(Ally),
+    /// An ally that isn't marked yet
+    Unmarked,
+}
+
+pub(super) struct Step {
+    /// Key of the prompt in the catalogs
+    pub(super) prompt: &'static str,
+    /// Actions that only move the cursor around
+    moves: &'static [Action],
+    /// The action that completes the step
+    finish: Action,
+    target: Target,
+}
+
+const DONE: &str = "tutorial.done";
+
+const PICK: &[Action] = &[Action::Next, Action::Prev];
+
+// the dice below are scripted so that every step can be completed, the
+// prompts name controls as `{next}`, `{confirm}`... and show the keys bound
+// to them
+static STEPS: [Step; 44] = [
+    Step {
+        prompt: "tutorial.start",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.fighter",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Fighter),
+    },
+    Step {
+        prompt: "tutorial.goblin",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Monster(Monster::Goblin),
+    },
+    Step {
+        prompt: "tutorial.fight_back",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.thief",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Thief),
+    },
+    Step {
+        prompt: "tutorial.chest",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Monster(Monster::Chest),
+    },
+    Step {
+        prompt: "tutorial.open",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.cleared",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.scroll",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Scroll),
+    },
+    Step {
+        prompt: "tutorial.up",
+        moves: &[],
+        finish: Action::Up,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.ooze",
+        moves: PICK,
+        finish: Action::Select,
+        target: Target::Reroll(Monster::Ooze),
+    },
+    Step {
+        prompt: "tutorial.marked",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.reroll",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.cleric",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Cleric),
+    },
+    Step {
+        prompt: "tutorial.skeleton",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Monster(Monster::Skeleton),
+    },
+    Step {
+        prompt: "tutorial.fight",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.mage",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Mage),
+    },
+    Step {
+        prompt: "tutorial.potion",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Monster(Monster::Potion),
+    },
+    Step {
+        prompt: "tutorial.revive_thief",
+        moves: PICK,
+        finish: Action::Select,
+        target: Target::Grave(Ally::Thief),
+    },
+    Step {
+        prompt: "tutorial.revive_cleric",
+        moves: PICK,
+        finish: Action::Select,
+        target: Target::Grave(Ally::Cleric),
+    },
+    Step {
+        prompt: "tutorial.revived",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.quaff",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.press_on",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.thief_ooze",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Thief),
+    },
+    Step {
+        prompt: "tutorial.pick_ooze",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Monster(Monster::Ooze),
+    },
+    Step {
+        prompt: "tutorial.fight",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.cleric_ooze",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Cleric),
+    },
+    Step {
+        prompt: "tutorial.pick_ooze",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Monster(Monster::Ooze),
+    },
+    Step {
+        prompt: "tutorial.fight",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.monster_defeat",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.empty",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.retire_down",
+        moves: &[],
+        finish: Action::Down,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.retire",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.skip_loot",
+        moves: &[],
+        finish: Action::Back,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.dragon_defeat",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.dragon_goblins",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Ally(Ally::Fighter),
+    },
+    Step {
+        prompt: "tutorial.pick_goblin",
+        moves: PICK,
+        finish: Action::Confirm,
+        target: Target::Monster(Monster::Goblin),
+    },
+    Step {
+        prompt: "tutorial.fight",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.mark_first",
+        moves: PICK,
+        finish: Action::Select,
+        target: Target::Unmarked,
+    },
+    Step {
+        prompt: "tutorial.mark_second",
+        moves: PICK,
+        finish: Action::Select,
+        target: Target::Unmarked,
+    },
+    Step {
+        prompt: "tutorial.mark_last",
+        moves: PICK,
+        finish: Action::Select,
+        target: Target::Unmarked,
+    },
+    Step {
+        prompt: "tutorial.face_dragon",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.strike",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+    Step {
+        prompt: "tutorial.victory",
+        moves: &[],
+        finish: Action::Confirm,
+        target: Target::Any,
+    },
+];
+
+impl Game<ScriptedDice> {
+    /// Sets up a game whose dice are rolled in advance, to be walked through
+    /// step by step
+    pub fn tutorial(mut dice: ScriptedDice) -> Self {
+        // a delve lost to monsters, an empty level and a retirement, a delve
+        // lost to the dragon and one that slays it
+        dice.queue([
+            Ally::Fighter,
+            Ally::Thief,
+            Ally::Scroll,
+            Ally::Cleric,
+            Ally::Mage,
+        ]);
+        dice.queue([
+            Ally::Fighter,
+            Ally::Cleric,
+            Ally::Mage,
+            Ally::Thief,
+            Ally::Champion,
+        ]);
+        dice.queue([
+            Ally::Scroll,
+            Ally::Scroll,
+            Ally::Scroll,
+            Ally::Fighter,
+            Ally::Thief,
+        ]);
+        dice.queue([
+            Ally::Fighter,
+            Ally::Cleric,
+            Ally::Mage,
+            Ally::Thief,
+            Ally::Champion,
+        ]);
+        // the first delve rolls two dice for the first level, three for the
+        // second, the scroll's reroll and four for the third, every later
+        // delve rolls one die more for its first level
+        dice.queue([
+            Monster::Goblin,
+            Monster::Chest,
+            Monster::Ooze,
+            Monster::Skeleton,
+            Monster::Potion,
+            Monster::Potion,
+            Monster::Ooze,
+            Monster::Ooze,
+            Monster::Ooze,
+            Monster::Ooze,
+        ]);
+        dice.queue([Monster::Dragon, Monster::Dragon, Monster::Dragon]);
+        dice.queue([
+            Monster::Dragon,
+            Monster::Dragon,
+            Monster::Dragon,
+            Monster::Chest,
+        ]);
+        dice.queue([
+            Monster::Dragon,
+            Monster::Dragon,
+            Monster::Dragon,
+            Monster::Goblin,
+            Monster::Goblin,
+        ]);
+
+        // enough XP for a party of five in every delve
+        let hero = Type::ALL[0];
+        let mut hero = Hero::new(hero.default_name().to_string(), hero);
+        hero.set_mode(Mode::Campaign);
+        hero.add_xp(5);
+
+        let mut game = Self::new(dice, Roster::from(vec![hero]), Rules::default());
+        game.tutorial = Some(0);
+
+        game
+    }
+}
+
+impl<R: DiceSource> Game<R> {
+    /// The step the tutorial is waiting on, if it's still running
+    pub(super) fn tutorial_step(&self) -> Option<&'static Step> {
+        self.tutorial.and_then(|i| STEPS.get(i))
+    }
+
+    /// What the tutorial asks of the player, with the keys of the active
+    /// keymap
+    pub fn tutorial_prompt(&self) -> Option<String> {
+        let prompt = self
+            .tutorial
+            .map(|i| STEPS.get(i).map_or(DONE, |s| s.prompt))?;
+
+        let keys = Control::ALL.map(|c| (c.name(), self.keymap.label(c)));
+        Some(self.language.format(prompt, &keys))
+    }
+
+    /// Whether the tutorial lets `action` through, advancing it when the
+    /// action completes the current step
+    pub(super) fn tutorial_allows(&mut self, action: Action) -> bool {
+        let Some(step) = self.tutorial_step() else {
+            // outside of a tutorial, or once it's over
+            return self.tutorial.is_none();
+        };
+
+        if step.moves.contains(&action) {
+            return true;
+        }
+        if action != step.finish || !self.on_target(&step.target) {
+            return false;
+        }
+
+        self.tutorial = self.tutorial.map(|i| i + 1);
+        true
+    }
+
+    fn on_target(&self, target: &Target) -> bool {
+        match target {
+            Target::Any => true,
+            Target::Ally(ally) => {
+                let i = self.party.cursor(PartyCursor::Ally as usize);
+                self.party.get(i) == Some(ally)
+            }
+            Target::Monster(monster) => {
+                let i = self.dungeon.cursor(DungeonCursor::Monster as usize);
+                self.dungeon.get(i) == Some(monster)
+            }
+            Target::Reroll(monster) => {
+                let i = self.dungeon.cursor(DungeonCursor::Reroll as usize);
+                self.dungeon.get(i) == Some(monster) && !self.dungeon.is_selected(i)
+            }
+            Target::Grave(ally) => {
+                let i = self.graveyard.cursor(0);
+                self.graveyard.get(i) == Some(ally) && !self.graveyard.is_selected(i)
+            }
+            Target::Unmarked => {
+                let i = self.party.cursor(PartyCursor::Ally as usize);
+                i < self.party.len() && !self.party.is_selected(i)
+            }
+        }
+    }
+}
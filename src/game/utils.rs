@@ -1,14 +1,15 @@
 use super::{
     Ally, Dice, DragonPhase, DungeonCursor, Game, LootPhase, Monster, MonsterPhase, PartyCursor,
-    Phase, Reroll, Rng,
+    Phase, Reroll,
 };
+use crate::dice_source::DiceSource;
 use std::{collections::HashSet, ops::Deref};
 
-pub fn roll<T: Dice, R: Rng>(rng: &mut R) -> T {
-    T::nth(rng.gen_range(0..T::faces()))
+pub fn roll<T: Dice + 'static, R: DiceSource>(rng: &mut R) -> T {
+    T::nth(rng.face::<T>())
 }
 
-pub fn roll_n<T: Dice, R: Rng>(rng: &mut R, n: u64) -> Vec<T> {
+pub fn roll_n<T: Dice + 'static, R: DiceSource>(rng: &mut R, n: u64) -> Vec<T> {
     (0..n).map(|_| roll(rng)).collect()
 }
 
@@ -20,14 +21,14 @@ pub fn indexes_of<T: PartialEq>(items: &[T], key: &T) -> Vec<usize> {
         .collect()
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Row {
     Party,
     Dungeon,
     Graveyard,
 }
 
-impl<R: Rng> Game<R> {
+impl<R: DiceSource> Game<R> {
     pub(super) fn current_monster(&self) -> &Monster {
         self.dungeon.value(DungeonCursor::Monster as usize)
     }
@@ -182,6 +183,21 @@ impl<T> Cursor<T> {
         }
     }
 
+    /// Whether cursor `c` is allowed on `index`
+    pub fn is_valid(&self, c: usize, index: usize) -> bool {
+        index < self.data.len() && self.invariants[c](self, index, &self.data[index])
+    }
+
+    /// Moves cursor `c` to `index` if the cursor's invariant allows it
+    pub fn set_cursor(&mut self, c: usize, index: usize) -> bool {
+        if self.is_valid(c, index) {
+            self.cursors[c] = index;
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn cursor(&self, c: usize) -> usize {
         assert!(c < self.cursors.len());
         self.cursors[c]
@@ -226,8 +242,11 @@ impl<T> Cursor<T> {
         ret
     }
 
+    /// Selected indices in ascending order
     pub fn selection(&self) -> Vec<usize> {
-        self.selection.iter().copied().collect()
+        let mut selection = self.selection.iter().copied().collect::<Vec<_>>();
+        selection.sort_unstable();
+        selection
     }
 
     pub fn clear_selection(&mut self) {
@@ -1,10 +1,26 @@
-#[derive(Debug)]
+use crate::{
+    locale::Language,
+    rules::{Mode, CLASSIC_DELVES},
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Level {
     Novice,
     Expert,
 }
 
-#[derive(Debug)]
+impl Level {
+    pub fn name(self, language: Language) -> Cow<'static, str> {
+        language.text(match self {
+            Level::Novice => "level.novice",
+            Level::Expert => "level.expert",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Type {
     Bard,
     Battlemage,
@@ -16,24 +32,112 @@ pub enum Type {
     Paladin,
 }
 
-#[derive(Debug)]
+impl Type {
+    pub const ALL: [Type; 8] = [
+        Type::Bard,
+        Type::Battlemage,
+        Type::Beguiler,
+        Type::Chieftain,
+        Type::Commander,
+        Type::DragonSlayer,
+        Type::Necromancer,
+        Type::Paladin,
+    ];
+
+    pub fn name(self, language: Language) -> Cow<'static, str> {
+        language.text(match self {
+            Type::Bard => "hero.type.bard",
+            Type::Battlemage => "hero.type.battlemage",
+            Type::Beguiler => "hero.type.beguiler",
+            Type::Chieftain => "hero.type.chieftain",
+            Type::Commander => "hero.type.commander",
+            Type::DragonSlayer => "hero.type.dragon_slayer",
+            Type::Necromancer => "hero.type.necromancer",
+            Type::Paladin => "hero.type.paladin",
+        })
+    }
+
+    /// "`name` the Type", or just the type when `name` is its English name
+    pub fn title(self, name: &str, language: Language) -> String {
+        if name == self.name(Language::English) {
+            self.name(language).into_owned()
+        } else {
+            language.format(
+                "hero.title",
+                &[
+                    ("name", name.to_string()),
+                    ("type", self.name(language).into_owned()),
+                ],
+            )
+        }
+    }
+
+    /// Name of the hero of this type in a new roster
+    pub fn default_name(self) -> &'static str {
+        match self {
+            Type::Bard => "Lyra",
+            Type::Battlemage => "Corvin",
+            Type::Beguiler => "Vesper",
+            Type::Chieftain => "Brunhild",
+            Type::Commander => "Aldric",
+            Type::DragonSlayer => "Sigrun",
+            Type::Necromancer => "Morwen",
+            Type::Paladin => "Tristan",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hero {
+    name: String,
     hero: Type,
     level: Level,
     xp: u64,
+    #[serde(default)]
+    delves: u64,
+    /// Delves since the current run started, a classic run is over after
+    /// `CLASSIC_DELVES` of them
+    #[serde(default)]
+    run_delves: u64,
+    #[serde(default)]
+    best_depth: u64,
+    #[serde(default)]
+    mode: Mode,
+    #[serde(skip)]
     ult_used: bool,
 }
 
 impl Hero {
-    pub fn new(hero: Type) -> Self {
+    pub fn new(name: String, hero: Type) -> Self {
         Self {
+            name,
             hero,
             level: Level::Novice,
             xp: 0,
+            delves: 0,
+            run_delves: 0,
+            best_depth: 0,
+            mode: Mode::default(),
             ult_used: false,
         }
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn hero(&self) -> Type {
+        self.hero
+    }
+
+    pub fn title(&self, language: Language) -> String {
+        self.hero.title(&self.name, language)
+    }
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
     pub fn xp(&self) -> u64 {
         self.xp
     }
@@ -41,4 +145,41 @@ impl Hero {
     pub fn add_xp(&mut self, amt: u64) {
         self.xp += amt;
     }
+
+    pub fn delves(&self) -> u64 {
+        self.delves
+    }
+
+    pub fn run_delves(&self) -> u64 {
+        self.run_delves
+    }
+
+    /// Starts a new run, unless a classic one is still under way
+    pub fn new_run(&mut self) {
+        if self.mode == Mode::Campaign || self.is_finished() {
+            self.run_delves = 0;
+        }
+    }
+
+    pub fn best_depth(&self) -> u64 {
+        self.best_depth
+    }
+
+    pub fn finish_delve(&mut self, depth: u64) {
+        self.delves += 1;
+        self.run_delves += 1;
+        self.best_depth = self.best_depth.max(depth);
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.mode == Mode::Classic && self.run_delves >= CLASSIC_DELVES
+    }
 }
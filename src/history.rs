@@ -0,0 +1,103 @@
+use crate::{hero::Type, locale::Language};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fs, io, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ending {
+    Retired,
+    Victory,
+    Monsters,
+    Dragon,
+}
+
+impl Ending {
+    pub fn describe(self, language: Language) -> Cow<'static, str> {
+        language.text(match self {
+            Ending::Retired => "ending.retired",
+            Ending::Victory => "ending.victory",
+            Ending::Monsters => "ending.monsters",
+            Ending::Dragon => "ending.dragon",
+        })
+    }
+}
+
+/// The outcome of a single delve
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    pub hero: String,
+    pub hero_type: Type,
+    pub seed: u64,
+    pub delve: u64,
+    pub level: u64,
+    pub xp: u64,
+    pub treasure: u64,
+    pub ending: Ending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sort {
+    Score,
+    Depth,
+    Recent,
+}
+
+impl Sort {
+    pub const ALL: [Sort; 3] = [Sort::Score, Sort::Depth, Sort::Recent];
+
+    pub fn name(self, language: Language) -> Cow<'static, str> {
+        language.text(match self {
+            Sort::Score => "sort.score",
+            Sort::Depth => "sort.depth",
+            Sort::Recent => "sort.recent",
+        })
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    runs: Vec<Run>,
+}
+
+impl History {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn store<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let s = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+
+    pub fn push(&mut self, run: Run) {
+        self.runs.push(run);
+    }
+
+    /// Runs of heroes of type `filter` (or all of them), best first and most
+    /// recent first among ties
+    pub fn leaderboard(&self, sort: Sort, filter: Option<Type>) -> Vec<&Run> {
+        let mut runs = self
+            .runs
+            .iter()
+            .rev()
+            .filter(|r| filter.is_none_or(|t| r.hero_type == t))
+            .collect::<Vec<_>>();
+
+        match sort {
+            Sort::Score => runs.sort_by_key(|r| std::cmp::Reverse((r.xp, r.level))),
+            Sort::Depth => runs.sort_by_key(|r| std::cmp::Reverse((r.level, r.xp))),
+            Sort::Recent => {}
+        }
+
+        runs
+    }
+}
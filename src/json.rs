@@ -0,0 +1,71 @@
+use crate::{
+    dice_source::DiceSource,
+    game::{Command, Game, Observation},
+    saves::Saves,
+};
+use serde::Serialize;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ErrorKind {
+    /// The line isn't a command object
+    Malformed,
+    /// The command can't be played right now
+    Illegal,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Response<'a> {
+    State {
+        observation: Observation<'a>,
+        legal: Vec<Command>,
+    },
+    Error {
+        kind: ErrorKind,
+        message: String,
+    },
+}
+
+fn state<R: DiceSource>(game: &Game<R>) -> Response<'_> {
+    Response::State {
+        observation: game.observe(),
+        legal: game.legal_commands(),
+    }
+}
+
+fn send(stdout: &mut impl Write, response: &Response) -> io::Result<()> {
+    serde_json::to_writer(&mut *stdout, response)?;
+    writeln!(stdout)?;
+    stdout.flush()
+}
+
+/// Plays by reading one command object per line from stdin and answering
+/// each with either the new state or an error, leaving the game untouched
+pub fn run<R: DiceSource>(mut game: Game<R>, saves: &Saves) -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    send(&mut stdout, &state(&game))?;
+
+    for line in stdin.lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            send(&mut stdout, &state(&game))?;
+            continue;
+        }
+
+        let result = serde_json::from_str::<Command>(&line)
+            .map_err(|e| (ErrorKind::Malformed, e.to_string()))
+            .and_then(|command| game.perform(&command).map_err(|e| (ErrorKind::Illegal, e)));
+        match result {
+            Ok(()) => send(&mut stdout, &state(&game))?,
+            Err((kind, message)) => send(&mut stdout, &Response::Error { kind, message })?,
+        }
+
+        saves.store(&mut game)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,308 @@
+use crate::game::Action;
+use crossterm::event::KeyCode;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, error::Error, fmt, fs, io, path::Path};
+
+#[derive(Debug)]
+pub enum KeymapError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Invalid(String),
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeymapError::Io(e) => write!(f, "could not read keymap file: {e}"),
+            KeymapError::Parse(e) => write!(f, "could not parse keymap file: {e}"),
+            KeymapError::Invalid(reason) => write!(f, "invalid keymap: {reason}"),
+        }
+    }
+}
+
+impl Error for KeymapError {}
+
+impl From<io::Error> for KeymapError {
+    fn from(e: io::Error) -> Self {
+        KeymapError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for KeymapError {
+    fn from(e: toml::de::Error) -> Self {
+        KeymapError::Parse(e)
+    }
+}
+
+/// What a key can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Next,
+    Prev,
+    Up,
+    Down,
+    Confirm,
+    Back,
+    Select,
+    Leaderboard,
+    Help,
+    Quit,
+}
+
+impl Control {
+    pub const ALL: [Control; 10] = [
+        Control::Next,
+        Control::Prev,
+        Control::Up,
+        Control::Down,
+        Control::Confirm,
+        Control::Back,
+        Control::Select,
+        Control::Leaderboard,
+        Control::Help,
+        Control::Quit,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Control::Next => "next",
+            Control::Prev => "prev",
+            Control::Up => "up",
+            Control::Down => "down",
+            Control::Confirm => "confirm",
+            Control::Back => "back",
+            Control::Select => "select",
+            Control::Leaderboard => "leaderboard",
+            Control::Help => "help",
+            Control::Quit => "quit",
+        }
+    }
+
+    /// The game action this control plays, if any
+    pub fn action(self) -> Option<Action> {
+        match self {
+            Control::Next => Some(Action::Next),
+            Control::Prev => Some(Action::Prev),
+            Control::Up => Some(Action::Up),
+            Control::Down => Some(Action::Down),
+            Control::Confirm => Some(Action::Confirm),
+            Control::Back => Some(Action::Back),
+            Control::Select => Some(Action::Select),
+            Control::Leaderboard | Control::Help | Control::Quit => None,
+        }
+    }
+}
+
+fn parse_key(name: &str) -> Result<KeyCode, KeymapError> {
+    let mut chars = name.chars();
+    match (name, chars.next(), chars.next()) {
+        ("Left", ..) => Ok(KeyCode::Left),
+        ("Right", ..) => Ok(KeyCode::Right),
+        ("Up", ..) => Ok(KeyCode::Up),
+        ("Down", ..) => Ok(KeyCode::Down),
+        ("Enter", ..) => Ok(KeyCode::Enter),
+        ("Esc", ..) => Ok(KeyCode::Esc),
+        ("Space", ..) => Ok(KeyCode::Char(' ')),
+        ("Tab", ..) => Ok(KeyCode::Tab),
+        ("Backspace", ..) => Ok(KeyCode::Backspace),
+        (_, Some(c), None) => Ok(KeyCode::Char(c)),
+        _ => Err(KeymapError::Invalid(format!("unknown key {name:?}"))),
+    }
+}
+
+/// The name `parse_key` reads back as `code`
+fn key_name(code: KeyCode) -> String {
+    match code {
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        _ => key_label(code),
+    }
+}
+
+#[allow(clippy::non_ascii_literal)]
+fn key_label(code: KeyCode) -> String {
+    match code {
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        _ => "?".to_string(),
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct KeymapFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preset: Option<String>,
+    /// Replaces the preset's keys of each control listed
+    bindings: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(KeyCode, Control)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                (KeyCode::Right, Control::Next),
+                (KeyCode::Left, Control::Prev),
+                (KeyCode::Up, Control::Up),
+                (KeyCode::Down, Control::Down),
+                (KeyCode::Enter, Control::Confirm),
+                (KeyCode::Esc, Control::Back),
+                (KeyCode::Char(' '), Control::Select),
+                (KeyCode::Tab, Control::Leaderboard),
+                (KeyCode::Char('?'), Control::Help),
+                (KeyCode::Char('q'), Control::Quit),
+            ],
+        }
+    }
+}
+
+impl Keymap {
+    pub const PRESETS: [&'static str; 3] = ["arrows", "vim", "wasd"];
+
+    /// The arrow keys, plus `hjkl` or `wasd` for movement
+    pub fn preset(name: &str) -> Option<Self> {
+        // next, prev, up and down
+        let extra = match name {
+            "arrows" => [].as_slice(),
+            "vim" => &['l', 'h', 'k', 'j'],
+            "wasd" => &['d', 'a', 'w', 's'],
+            _ => return None,
+        };
+
+        let mut keymap = Self::default();
+        for (c, control) in extra.iter().zip(Control::ALL) {
+            keymap.bindings.push((KeyCode::Char(*c), control));
+        }
+
+        Some(keymap)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, KeymapError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Reads a keymap file, see `load`
+    pub fn parse(source: &str) -> Result<Self, KeymapError> {
+        let file: KeymapFile = toml::from_str(source)?;
+
+        let preset = file.preset.as_deref().unwrap_or("arrows");
+        let mut keymap = Self::preset(preset)
+            .ok_or_else(|| KeymapError::Invalid(format!("unknown preset {preset:?}")))?;
+
+        if let Some(name) = file
+            .bindings
+            .keys()
+            .find(|n| Control::ALL.iter().all(|c| c.name() != *n))
+        {
+            return Err(KeymapError::Invalid(format!("unknown control {name:?}")));
+        }
+
+        // applied in a fixed order so the controls bar doesn't shuffle
+        for control in Control::ALL {
+            if let Some(keys) = file.bindings.get(control.name()) {
+                keymap.bindings.retain(|(_, c)| *c != control);
+                for key in keys {
+                    keymap.bindings.push((parse_key(key)?, control));
+                }
+            }
+        }
+        keymap.validate()?;
+
+        Ok(keymap)
+    }
+
+    /// The keymap as a file that `parse` reads back, every control listed
+    ///
+    /// # Panics
+    ///
+    /// Never, a keymap file only holds strings
+    pub fn to_toml(&self) -> String {
+        let file = KeymapFile {
+            preset: None,
+            bindings: Control::ALL
+                .iter()
+                .map(|control| {
+                    let keys = self
+                        .bindings
+                        .iter()
+                        .filter(|(_, c)| c == control)
+                        .map(|(k, _)| key_name(*k))
+                        .collect();
+                    (control.name().to_string(), keys)
+                })
+                .collect(),
+        };
+
+        toml::to_string(&file).expect("keymaps only hold strings")
+    }
+
+    pub fn validate(&self) -> Result<(), KeymapError> {
+        for (i, (key, control)) in self.bindings.iter().enumerate() {
+            // digits jump to the die with that number
+            if matches!(key, KeyCode::Char(c) if c.is_ascii_digit()) {
+                return Err(KeymapError::Invalid(format!(
+                    "{} is bound to both a die and {}",
+                    key_label(*key),
+                    control.name()
+                )));
+            }
+
+            if let Some((_, other)) = self.bindings[..i]
+                .iter()
+                .find(|(k, c)| k == key && c != control)
+            {
+                return Err(KeymapError::Invalid(format!(
+                    "{} is bound to both {} and {}",
+                    key_label(*key),
+                    other.name(),
+                    control.name()
+                )));
+            }
+        }
+
+        if let Some(control) = Control::ALL
+            .iter()
+            .find(|c| self.bindings.iter().all(|(_, b)| b != *c))
+        {
+            return Err(KeymapError::Invalid(format!(
+                "no key is bound to {}",
+                control.name()
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn control(&self, code: KeyCode) -> Option<Control> {
+        self.bindings
+            .iter()
+            .find(|(k, _)| *k == code)
+            .map(|(_, c)| *c)
+    }
+
+    /// Every key bound to `control`, as shown in the controls bar
+    pub fn label(&self, control: Control) -> String {
+        self.bindings
+            .iter()
+            .filter(|(_, c)| *c == control)
+            .map(|(k, _)| key_label(*k))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
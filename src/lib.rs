@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![deny(clippy::pedantic)]
+#![allow(clippy::must_use_candidate, clippy::missing_errors_doc)]
+
+#[macro_use]
+extern crate dice_derive;
+#[macro_use]
+extern crate lazy_static;
+
+pub mod achievements;
+pub mod args;
+pub mod config;
+pub mod daily;
+pub mod dice;
+pub mod dice_source;
+pub mod env;
+pub mod ffi;
+pub mod game;
+pub mod hero;
+pub mod history;
+pub mod json;
+pub mod keymap;
+pub mod locale;
+pub mod net;
+pub mod phase;
+pub mod roster;
+pub mod rules;
+pub mod saves;
+pub mod scenario;
+pub mod text;
+pub mod theme;
+pub mod treasure;
+pub mod ui;
@@ -0,0 +1,163 @@
+use serde::Deserialize;
+use std::{borrow::Cow, collections::HashMap, env, sync::OnceLock};
+use tui::{
+    style::Style,
+    text::{Span, Spans},
+};
+
+/// The bundled catalogs, parsed the first time any text is looked up
+fn catalogs() -> &'static HashMap<Language, HashMap<String, String>> {
+    static CATALOGS: OnceLock<HashMap<Language, HashMap<String, String>>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        Language::ALL
+            .into_iter()
+            .map(|l| (l, parse(l.source()).expect("bundled catalogs are valid")))
+            .collect()
+    })
+}
+
+/// Language the game text is shown in, each one has a catalog in `locales/`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "es")]
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|l| l.name() == name)
+    }
+
+    /// The language of a locale such as `es_ES.UTF-8`
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let name = locale.split(['_', '.', '@']).next()?;
+        Self::from_name(&name.to_lowercase())
+    }
+
+    /// The language of `LANG`, English if it's unset or has no catalog
+    pub fn from_env() -> Self {
+        env::var("LANG")
+            .ok()
+            .and_then(|l| Self::from_locale(&l))
+            .unwrap_or_default()
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => {
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/locales/en.toml"))
+            }
+            Language::Spanish => {
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/locales/es.toml"))
+            }
+        }
+    }
+
+    /// Every key in the catalog, sorted
+    pub fn keys(self) -> Vec<&'static str> {
+        let mut keys = catalogs()[&self]
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+        keys
+    }
+
+    /// The text for `key`, in English if this catalog is missing it and the
+    /// key itself if every catalog is
+    pub fn text(self, key: &str) -> Cow<'static, str> {
+        let catalogs = catalogs();
+        catalogs[&self]
+            .get(key)
+            .or_else(|| catalogs[&Language::English].get(key))
+            .map_or_else(
+                || Cow::Owned(key.to_string()),
+                |t| Cow::Borrowed(t.as_str()),
+            )
+    }
+
+    /// The text for `key` with its `{name}` placeholders filled from `args`
+    pub fn format(self, key: &str, args: &[(&str, String)]) -> String {
+        let mut text = self.text(key).into_owned();
+        for (name, value) in args {
+            text = text.replace(&format!("{{{name}}}"), value);
+        }
+        text
+    }
+
+    /// The text for `key` with its `{name}` placeholders filled from `args`,
+    /// and its `[text](die)` links drawn in the style `style` gives the die
+    pub fn spans(
+        self,
+        key: &str,
+        args: &[(&str, String)],
+        style: impl Fn(&str) -> Style,
+    ) -> Spans<'static> {
+        let text = self.format(key, args);
+
+        let mut spans = Vec::new();
+        let mut rest = text.as_str();
+        while let Some((before, link, die, after)) = split_link(rest) {
+            if !before.is_empty() {
+                spans.push(Span::raw(before.to_string()));
+            }
+            spans.push(Span::styled(link.to_string(), style(die)));
+            rest = after;
+        }
+        if !rest.is_empty() {
+            spans.push(Span::raw(rest.to_string()));
+        }
+
+        Spans::from(spans)
+    }
+}
+
+/// Splits `text` around its first `[link](die)`
+fn split_link(text: &str) -> Option<(&str, &str, &str, &str)> {
+    let (before, rest) = text.split_once('[')?;
+    let (link, rest) = rest.split_once("](")?;
+    let (die, after) = rest.split_once(')')?;
+    Some((before, link, die, after))
+}
+
+/// Flattens the tables of a catalog into dotted keys
+fn parse(source: &str) -> Result<HashMap<String, String>, String> {
+    fn flatten(
+        prefix: &str,
+        value: toml::Value,
+        catalog: &mut HashMap<String, String>,
+    ) -> Result<(), String> {
+        match value {
+            toml::Value::String(s) => {
+                catalog.insert(prefix.to_string(), s);
+                Ok(())
+            }
+            toml::Value::Table(table) => table.into_iter().try_for_each(|(k, v)| {
+                let key = if prefix.is_empty() {
+                    k
+                } else {
+                    format!("{prefix}.{k}")
+                };
+                flatten(&key, v, catalog)
+            }),
+            _ => Err(format!("{prefix} is not text")),
+        }
+    }
+
+    let value = toml::from_str(source).map_err(|e| e.to_string())?;
+    let mut catalog = HashMap::new();
+    flatten("", value, &mut catalog)?;
+    Ok(catalog)
+}
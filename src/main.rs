@@ -2,64 +2,135 @@
 #![allow(unused_variables)]
 #![deny(clippy::pedantic)]
 
-use crossterm::{
-    event::EventStream,
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use crossterm::event::EventStream;
 use futures::StreamExt;
 use rand::prelude::*;
 use rand_pcg::Pcg64Mcg;
-use std::{io, time::Duration};
+use std::{io, process};
 use tokio::time::sleep;
-use tui::{backend::CrosstermBackend, Terminal};
-
-#[macro_use]
-extern crate dice_derive;
-#[macro_use]
-extern crate lazy_static;
-
-mod dice;
-mod game;
-mod hero;
-mod phase;
-mod treasure;
 
-use game::Game;
-use hero::Type;
+use dungeon_roll::{
+    achievements::Achievements,
+    args::{Args, Frontend},
+    config::Config,
+    daily::{Daily, Records},
+    dice_source::ScriptedDice,
+    game::Game,
+    history::History,
+    json, net,
+    roster::{data_dir, Roster},
+    saves::Saves,
+    text, ui,
+};
 
 #[tokio::main]
 async fn main() -> Result<(), io::Error> {
-    let mut seed: <Pcg64Mcg as SeedableRng>::Seed = Default::default();
-    thread_rng().fill(&mut seed);
-    let rng = Pcg64Mcg::from_seed(seed);
-    let mut game = Game::new(rng, Type::Bard);
+    let args = Args::parse().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let data_dir = args.data_dir.unwrap_or_else(data_dir);
+    let mut saves = Saves::new(&data_dir);
+    if let Some(roster) = args.roster {
+        saves.roster = roster;
+    }
+    let mut config = Config::load(data_dir.join("config.toml"))?;
+    if let Some(theme) = args.theme {
+        config.theme = theme;
+    }
+    if let Some(animations) = args.animations {
+        config.animations = animations;
+    }
+
+    if let Some(addr) = args.connect {
+        return net::client::run(&addr, args.seat, args.spectate, args.keymap, &config).await;
+    }
+
+    if let Some(addr) = args.serve {
+        let seed = args.seed.unwrap_or_else(|| thread_rng().gen());
+        let roster = Roster::load(&saves.roster)?;
+        return net::server::run(&addr, seed, args.rules, roster, saves.roster).await;
+    }
+
+    // runs are recorded with their seed, so it has to fit in a u64
+    let seed = args.seed.unwrap_or_else(|| thread_rng().gen());
+    // only the tutorial queues faces, every other game just rolls them
+    let rng = ScriptedDice::new(Pcg64Mcg::seed_from_u64(seed));
+    let daily_path = data_dir.join("daily.toml");
+    let mut daily_records = None;
+
+    let mut game = if args.daily {
+        let daily = Daily::today();
+        let records = Records::load(&daily_path)?;
+        if let Some(run) = records.find(&daily.date) {
+            eprintln!(
+                "You already took the challenge of {}, scoring {} XP. Come back tomorrow!",
+                run.date, run.score
+            );
+            process::exit(1);
+        }
+
+        daily_records = Some(records);
+        let seed = daily.seed;
+        let mut game = Game::with_daily(ScriptedDice::new(Pcg64Mcg::seed_from_u64(seed)), daily);
+        game.track_history(History::load(&saves.history)?, seed);
+        game.track_achievements(Achievements::load(&saves.achievements)?);
+        game
+    } else if args.tutorial {
+        Game::tutorial(rng)
+    } else if let Some(scenario) = args.scenario {
+        Game::with_scenario(rng, args.rules, scenario)
+    } else {
+        let mut game = Game::new(rng, Roster::load(&saves.roster)?, args.rules);
+        game.track_history(History::load(&saves.history)?, seed);
+        game.track_achievements(Achievements::load(&saves.achievements)?);
+        game
+    };
 
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    match args.frontend {
+        Frontend::Text => return text::run(game, &saves),
+        Frontend::Json => return json::run(game, &saves),
+        Frontend::Terminal => (),
+    }
 
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    game.set_keymap(args.keymap);
+    game.set_theme(config.theme);
+    game.set_language(config.language());
+    game.set_animations(config.animations);
+    let mut terminal = ui::enter()?;
     let mut reader = EventStream::new();
 
-    terminal.clear()?;
-    loop {
-        game.render(&mut terminal)?;
+    // the terminal is restored before any error is reported
+    let result = async {
+        loop {
+            game.render(&mut terminal)?;
 
-        tokio::select! {
-            _ = sleep(Duration::from_millis(500)) => game.toggle_blink(),
-            maybe_event = reader.next() => match maybe_event {
-                Some(Ok(event)) => if game.handle_event(event) {break;},
-                Some(Err(e)) => println!("Error: {:?}\r", e),
-                None => break,
+            tokio::select! {
+                _ = sleep(game.tick_rate()) => game.tick(),
+                maybe_event = reader.next() => match maybe_event {
+                    Some(Ok(event)) => if game.handle_event(event) {break;},
+                    Some(Err(e)) => println!("Error: {:?}\r", e),
+                    None => break,
+                }
+            }
+
+            saves.store(&mut game)?;
+
+            // the run is recorded as soon as it starts so quitting can't be
+            // used to retry
+            if let (Some(records), Some(run)) = (&mut daily_records, game.daily_run()) {
+                if records.find(&run.date) != Some(&run) {
+                    records.record(run);
+                    records.store(&daily_path)?;
+                }
             }
         }
-    }
 
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+        Ok(())
+    }
+    .await;
+    ui::leave(&mut terminal)?;
 
-    Ok(())
+    result
 }
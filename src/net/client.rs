@@ -0,0 +1,211 @@
+use super::{decode, encode, ClientMessage, Entry, ServerMessage};
+use crate::{
+    config::Config,
+    dice_source::Replay,
+    game::Game,
+    keymap::{Control, Keymap},
+    ui::{self, Tui},
+};
+use crossterm::event::{Event, EventStream};
+use futures::StreamExt;
+use std::io;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpStream,
+    },
+    time::sleep,
+};
+
+pub struct Connection {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+}
+
+impl Connection {
+    pub async fn send(&mut self, message: &ClientMessage) -> io::Result<()> {
+        self.writer.write_all(encode(message)?.as_bytes()).await
+    }
+
+    /// The next message from the server, `None` once it hangs up
+    pub async fn recv(&mut self) -> io::Result<Option<ServerMessage>> {
+        match self.lines.next_line().await? {
+            Some(line) => decode(&line).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// What a client knows about the game it joined
+pub struct Session {
+    seat: Option<usize>,
+    game: Game<Replay>,
+    log: Vec<Entry>,
+}
+
+impl Session {
+    pub fn seat(&self) -> Option<usize> {
+        self.seat
+    }
+
+    pub fn game(&self) -> &Game<Replay> {
+        &self.game
+    }
+
+    pub fn apply(&mut self, mut entry: Entry) {
+        self.game.rng_mut().push(std::mem::take(&mut entry.drawn));
+        self.game.apply(entry.action);
+        self.log.push(entry);
+    }
+
+    /// Applies the local preferences to the game rebuilt from the server
+    fn customize(&mut self, keymap: &Keymap, config: &Config) {
+        self.game.set_keymap(keymap.clone());
+        self.game.set_theme(config.theme);
+        self.game.set_language(config.language());
+        self.game.set_animations(config.animations);
+    }
+}
+
+/// Joins the game at `addr` and rebuilds it from the server's welcome
+pub async fn connect(
+    addr: &str,
+    seat: Option<usize>,
+    spectate: bool,
+) -> io::Result<(Connection, Session)> {
+    let (reader, writer) = TcpStream::connect(addr).await?.into_split();
+    let mut conn = Connection {
+        lines: BufReader::new(reader).lines(),
+        writer,
+    };
+    conn.send(&ClientMessage::Hello { seat, spectate }).await?;
+
+    let message = conn.recv().await?.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::UnexpectedEof, "server closed the connection")
+    })?;
+
+    match message {
+        ServerMessage::Welcome {
+            seat,
+            rules,
+            roster,
+            log,
+        } => {
+            let mut session = Session {
+                seat,
+                game: Game::new(Replay::default(), roster, rules),
+                log: Vec::new(),
+            };
+            for entry in log {
+                session.apply(entry);
+            }
+
+            Ok((conn, session))
+        }
+        ServerMessage::Rejected { reason } => Err(io::Error::other(reason)),
+        ServerMessage::Action(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "expected a welcome",
+        )),
+    }
+}
+
+pub async fn run(
+    addr: &str,
+    seat: Option<usize>,
+    spectate: bool,
+    keymap: Keymap,
+    config: &Config,
+) -> io::Result<()> {
+    let (conn, mut session) = connect(addr, seat, spectate).await?;
+    session.customize(&keymap, config);
+
+    let mut terminal = ui::enter()?;
+    let result = play(addr, conn, session, &mut terminal, &keymap, config).await;
+    ui::leave(&mut terminal)?;
+
+    result
+}
+
+async fn play(
+    addr: &str,
+    conn: Connection,
+    mut session: Session,
+    terminal: &mut Tui,
+    keymap: &Keymap,
+    config: &Config,
+) -> io::Result<()> {
+    let mut conn = Some(conn);
+    let mut reader = EventStream::new();
+
+    loop {
+        match session.seat {
+            Some(_) => session.game.render(terminal)?,
+            None => {
+                let log: Vec<_> = session
+                    .log
+                    .iter()
+                    .map(|entry| entry.describe(config.language()))
+                    .collect();
+                session.game.render_spectator(terminal, &log)?
+            }
+        };
+
+        tokio::select! {
+            () = sleep(session.game.tick_rate()) => {
+                session.game.tick();
+
+                // try to take our seat back after losing the connection
+                if conn.is_none() {
+                    if let Ok((c, s)) = connect(addr, session.seat, session.seat.is_none()).await {
+                        conn = Some(c);
+                        session = s;
+                        session.customize(keymap, config);
+                    }
+                }
+            }
+            maybe_event = reader.next() => match maybe_event {
+                Some(Ok(Event::Key(kc))) if keymap.control(kc.code) == Some(Control::Quit) => break,
+                // the help and the leaderboard are never sent to the server
+                Some(Ok(event)) if session.game.handle_local(&event) => (),
+                // the dice settle before anything else is played
+                Some(Ok(Event::Key(kc))) if session.game.is_rolling() => {
+                    if keymap.control(kc.code) == Some(Control::Confirm) {
+                        session.game.skip_roll();
+                    }
+                }
+                Some(Ok(event)) if session.seat.is_some() && !session.game.is_rolling() => {
+                    let actions = match event {
+                        Event::Key(kc) => match keymap.control(kc.code) {
+                            Some(control) => control.action().into_iter().collect(),
+                            None => session.game.jump_key(kc.code),
+                        },
+                        Event::Mouse(me) => session.game.click(me),
+                        Event::Resize(..) => Vec::new(),
+                    };
+                    for action in actions {
+                        if let Some(c) = conn.as_mut() {
+                            if c.send(&ClientMessage::Action { action }).await.is_err() {
+                                conn = None;
+                            }
+                        }
+                    }
+                }
+                Some(Ok(_)) => (),
+                Some(Err(e)) => return Err(e),
+                None => break,
+            },
+            message = async { conn.as_mut().unwrap().recv().await }, if conn.is_some() => {
+                match message {
+                    Ok(Some(ServerMessage::Action(entry))) => session.apply(entry),
+                    // rejections only concern actions the server ignored
+                    Ok(Some(_)) => (),
+                    Ok(None) | Err(_) => conn = None,
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::{game::Action, locale::Language, roster::Roster, rules::Rules};
+use serde::{Deserialize, Serialize};
+use std::io;
+
+pub mod client;
+pub mod server;
+
+/// Messages sent by clients, one JSON object per line
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// First message of every connection, `seat` reclaims a seat after a
+    /// disconnect and `spectate` joins without a seat
+    Hello {
+        seat: Option<usize>,
+        #[serde(default)]
+        spectate: bool,
+    },
+    Action {
+        action: Action,
+    },
+}
+
+/// Messages sent by the server, one JSON object per line
+///
+/// Clients never run the rules on their own input, they rebuild the game
+/// from the rules and roster in `Welcome` and then apply every action the
+/// server accepts, in order. The seed stays on the server, so that nobody
+/// can tell the next roll in advance
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// `seat` is `None` for spectators
+    Welcome {
+        seat: Option<usize>,
+        rules: Rules,
+        roster: Roster,
+        log: Vec<Entry>,
+    },
+    Action(Entry),
+    Rejected {
+        reason: String,
+    },
+}
+
+/// An action accepted by the server, the seat that played it and the
+/// numbers the server drew while applying it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub seat: usize,
+    pub action: Action,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub drawn: Vec<u64>,
+}
+
+impl Entry {
+    pub fn describe(&self, language: Language) -> String {
+        language.format(
+            "playfield.log_entry",
+            &[
+                ("player", (self.seat + 1).to_string()),
+                ("action", self.action.name(language).into_owned()),
+            ],
+        )
+    }
+}
+
+pub fn encode<T: Serialize>(message: &T) -> io::Result<String> {
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    Ok(line)
+}
+
+pub fn decode<'a, T: Deserialize<'a>>(line: &'a str) -> io::Result<T> {
+    Ok(serde_json::from_str(line)?)
+}
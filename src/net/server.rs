@@ -0,0 +1,258 @@
+use super::{decode, encode, ClientMessage, Entry, ServerMessage};
+use crate::{
+    dice_source::Recorder,
+    game::{Action, Game, MAX_PLAYERS},
+    roster::Roster,
+    rules::Rules,
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+use std::{
+    io,
+    path::PathBuf,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::mpsc::{self, UnboundedSender},
+};
+
+#[derive(Clone, Copy)]
+enum Role {
+    Player(usize),
+    Spectator(usize),
+}
+
+struct State {
+    game: Game<Recorder>,
+    rules: Rules,
+    // the roster as it was when the game started, clients replay from it
+    roster: Roster,
+    roster_path: PathBuf,
+    log: Vec<Entry>,
+    seats: Vec<Option<UnboundedSender<String>>>,
+    spectators: Vec<Option<UnboundedSender<String>>>,
+}
+
+impl State {
+    fn send(&self, role: Role, message: &ServerMessage) {
+        let tx = match role {
+            Role::Player(seat) => self.seats.get(seat),
+            Role::Spectator(id) => self.spectators.get(id),
+        };
+
+        if let (Some(Some(tx)), Ok(line)) = (tx, encode(message)) {
+            // a closed channel means the client is leaving, which is handled
+            // by its connection task
+            let _ = tx.send(line);
+        }
+    }
+
+    fn broadcast(&self, message: &ServerMessage) {
+        for seat in 0..self.seats.len() {
+            self.send(Role::Player(seat), message);
+        }
+
+        for id in 0..self.spectators.len() {
+            self.send(Role::Spectator(id), message);
+        }
+    }
+
+    fn join(
+        &mut self,
+        seat: Option<usize>,
+        spectate: bool,
+        tx: UnboundedSender<String>,
+    ) -> Result<Role, String> {
+        let role = match seat {
+            _ if spectate => {
+                let id = self
+                    .spectators
+                    .iter()
+                    .position(Option::is_none)
+                    .unwrap_or(self.spectators.len());
+                if id == self.spectators.len() {
+                    self.spectators.push(None);
+                }
+
+                self.spectators[id] = Some(tx);
+                Role::Spectator(id)
+            }
+            Some(s) if s >= MAX_PLAYERS => return Err(format!("there is no seat {s}")),
+            Some(s) if self.seats[s].is_some() => return Err(format!("seat {s} is taken")),
+            Some(s) => {
+                self.seats[s] = Some(tx);
+                Role::Player(s)
+            }
+            None => {
+                let s = self
+                    .seats
+                    .iter()
+                    .position(Option::is_none)
+                    .ok_or_else(|| "the game is full".to_string())?;
+                self.seats[s] = Some(tx);
+                Role::Player(s)
+            }
+        };
+
+        self.send(
+            role,
+            &ServerMessage::Welcome {
+                seat: match role {
+                    Role::Player(seat) => Some(seat),
+                    Role::Spectator(_) => None,
+                },
+                rules: self.rules.clone(),
+                roster: self.roster.clone(),
+                log: self.log.clone(),
+            },
+        );
+
+        Ok(role)
+    }
+
+    fn leave(&mut self, role: Role) {
+        match role {
+            Role::Player(seat) => self.seats[seat] = None,
+            Role::Spectator(id) => self.spectators[id] = None,
+        }
+    }
+
+    fn act(&mut self, role: Role, action: Action) -> io::Result<()> {
+        let seat = match role {
+            Role::Player(seat) if seat == self.game.active_player() => seat,
+            Role::Player(_) => {
+                let reason = "it is not your turn".to_string();
+                self.send(role, &ServerMessage::Rejected { reason });
+                return Ok(());
+            }
+            Role::Spectator(_) => {
+                let reason = "spectators can't play".to_string();
+                self.send(role, &ServerMessage::Rejected { reason });
+                return Ok(());
+            }
+        };
+
+        self.game.apply(action);
+        let entry = Entry {
+            seat,
+            action,
+            drawn: self.game.rng_mut().take(),
+        };
+        self.broadcast(&ServerMessage::Action(entry.clone()));
+        self.log.push(entry);
+
+        if let Some(roster) = self.game.take_checkpoint() {
+            roster.store(&self.roster_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs a headless server for a game, clients take the seats in order and
+/// any number of spectators can watch
+pub async fn run(
+    addr: &str,
+    seed: u64,
+    rules: Rules,
+    roster: Roster,
+    roster_path: PathBuf,
+) -> io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    eprintln!("Listening on {} with seed {seed}", listener.local_addr()?);
+
+    serve(listener, seed, rules, roster, roster_path).await
+}
+
+/// Like `run`, on a listener that is already bound
+pub async fn serve(
+    listener: TcpListener,
+    seed: u64,
+    rules: Rules,
+    roster: Roster,
+    roster_path: PathBuf,
+) -> io::Result<()> {
+    let state = Arc::new(Mutex::new(State {
+        game: Game::new(
+            Recorder::new(Pcg64Mcg::seed_from_u64(seed)),
+            roster.clone(),
+            rules.clone(),
+        ),
+        rules,
+        roster,
+        roster_path,
+        log: Vec::new(),
+        seats: vec![None; MAX_PLAYERS],
+        spectators: Vec::new(),
+    }));
+
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let state = state.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle(stream, &state).await {
+                eprintln!("{peer}: {e}");
+            }
+        });
+    }
+}
+
+async fn handle(stream: TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    tokio::spawn(async move {
+        while let Some(line) = rx.recv().await {
+            if writer.write_all(line.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let role = match lines.next_line().await? {
+        Some(line) => match decode(&line)? {
+            ClientMessage::Hello { seat, spectate } => lock(state).join(seat, spectate, tx.clone()),
+            ClientMessage::Action { .. } => Err("expected a hello".to_string()),
+        },
+        None => return Ok(()),
+    };
+
+    let role = match role {
+        Ok(role) => role,
+        Err(reason) => {
+            let _ = tx.send(encode(&ServerMessage::Rejected { reason })?);
+            return Ok(());
+        }
+    };
+
+    let result = async {
+        while let Some(line) = lines.next_line().await? {
+            let reason = match decode(&line) {
+                Ok(ClientMessage::Action { action }) => {
+                    lock(state).act(role, action)?;
+                    continue;
+                }
+                Ok(ClientMessage::Hello { .. }) => "already joined".to_string(),
+                Err(e) => e.to_string(),
+            };
+
+            lock(state).send(role, &ServerMessage::Rejected { reason });
+        }
+
+        Ok(())
+    }
+    .await;
+
+    lock(state).leave(role);
+
+    result
+}
+
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    // the state is only ever mutated through complete actions, so it's still
+    // consistent if another connection panicked while holding the lock
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -1,12 +1,15 @@
 #[derive(Debug, PartialEq, Eq)]
 pub enum Phase {
+    Start,
     Setup,
     Monster(Monster),
     Loot(Loot),
     Dragon(Dragon),
     EmptyDungeon,
     Regroup(Regroup),
+    Scoreboard,
     Victory,
+    GameOver,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -0,0 +1,82 @@
+use crate::hero::{Hero, Type};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    ops::{Deref, DerefMut},
+    path::{Path, PathBuf},
+};
+
+/// Where the game keeps its files unless told otherwise
+pub fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("dungeon-roll")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Roster {
+    heroes: Vec<Hero>,
+}
+
+impl Default for Roster {
+    fn default() -> Self {
+        Self {
+            heroes: Type::ALL
+                .iter()
+                .map(|t| Hero::new(t.default_name().to_string(), *t))
+                .collect(),
+        }
+    }
+}
+
+impl Roster {
+    /// Loads the roster at `path`, falling back to one hero of each type if
+    /// there is no roster yet
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let roster: Roster = match fs::read_to_string(path) {
+            Ok(s) => {
+                toml::from_str(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Roster::default()),
+            Err(e) => return Err(e),
+        };
+
+        if roster.heroes.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the roster has no heroes",
+            ));
+        }
+
+        Ok(roster)
+    }
+
+    pub fn store<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let s = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, s)
+    }
+}
+
+impl From<Vec<Hero>> for Roster {
+    fn from(heroes: Vec<Hero>) -> Self {
+        Self { heroes }
+    }
+}
+
+impl Deref for Roster {
+    type Target = Vec<Hero>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.heroes
+    }
+}
+
+impl DerefMut for Roster {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.heroes
+    }
+}
@@ -0,0 +1,111 @@
+use crate::locale::Language;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, error::Error, fmt, fs, io, path::Path};
+
+#[derive(Debug)]
+pub enum RulesError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Invalid(&'static str),
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RulesError::Io(e) => write!(f, "could not read rules file: {e}"),
+            RulesError::Parse(e) => write!(f, "could not parse rules file: {e}"),
+            RulesError::Invalid(reason) => write!(f, "invalid rules: {reason}"),
+        }
+    }
+}
+
+impl Error for RulesError {}
+
+impl From<io::Error> for RulesError {
+    fn from(e: io::Error) -> Self {
+        RulesError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for RulesError {
+    fn from(e: toml::de::Error) -> Self {
+        RulesError::Parse(e)
+    }
+}
+
+/// Party size of classic mode, which `max_party_size` only caps for campaigns
+pub const CLASSIC_PARTY_SIZE: u64 = 7;
+pub const CLASSIC_DELVES: u64 = 3;
+/// Largest `max_party_size` accepted, more allies wouldn't fit on screen
+pub const PARTY_SIZE_LIMIT: u64 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    #[default]
+    Campaign,
+    Classic,
+}
+
+impl Mode {
+    pub fn name(self, language: Language) -> Cow<'static, str> {
+        language.text(match self {
+            Mode::Campaign => "mode.campaign",
+            Mode::Classic => "mode.classic",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules {
+    pub name: String,
+    pub max_party_size: u64,
+    pub max_dungeon_size: u64,
+    pub dragon_threshold: u64,
+    pub xp_per_level: u64,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            name: "Standard".to_string(),
+            max_party_size: 7,
+            max_dungeon_size: 10,
+            dragon_threshold: 3,
+            xp_per_level: 1,
+        }
+    }
+}
+
+impl Rules {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RulesError> {
+        let rules: Rules = toml::from_str(&fs::read_to_string(path)?)?;
+        rules.validate()?;
+
+        Ok(rules)
+    }
+
+    pub fn validate(&self) -> Result<(), RulesError> {
+        // the dragon needs three companions to be defeated
+        if self.max_party_size < 3 {
+            return Err(RulesError::Invalid("max_party_size must be at least 3"));
+        }
+
+        if self.max_party_size > PARTY_SIZE_LIMIT {
+            return Err(RulesError::Invalid("max_party_size must be at most 20"));
+        }
+
+        if self.max_dungeon_size == 0 {
+            return Err(RulesError::Invalid("max_dungeon_size must be at least 1"));
+        }
+
+        if self.dragon_threshold == 0 || self.dragon_threshold > self.max_dungeon_size {
+            return Err(RulesError::Invalid(
+                "dragon_threshold must be between 1 and max_dungeon_size",
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{dice_source::DiceSource, game::Game};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Where the progress of a local game is written as it changes
+#[derive(Debug, Clone)]
+pub struct Saves {
+    pub roster: PathBuf,
+    pub history: PathBuf,
+    pub achievements: PathBuf,
+}
+
+impl Saves {
+    /// Every file in `dir`
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            roster: dir.join("roster.toml"),
+            history: dir.join("history.toml"),
+            achievements: dir.join("achievements.toml"),
+        }
+    }
+
+    /// Writes whatever `game` changed since the last call
+    pub fn store<R: DiceSource>(&self, game: &mut Game<R>) -> io::Result<()> {
+        if let Some(roster) = game.take_checkpoint() {
+            roster.store(&self.roster)?;
+        }
+
+        if let Some(history) = game.take_history() {
+            history.store(&self.history)?;
+        }
+
+        if let Some(achievements) = game.take_achievements() {
+            achievements.store(&self.achievements)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    dice::{Ally, Monster},
+    hero::Type,
+    locale::Language,
+    rules::Rules,
+    treasure::Treasure,
+};
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt, fs, io, path::Path};
+
+#[derive(Debug)]
+pub enum ScenarioError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Invalid(&'static str),
+    /// Starts on the dragon with fewer dragon dice than the rules need
+    DragonThreshold(u64),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScenarioError::Io(e) => write!(f, "could not read scenario file: {e}"),
+            ScenarioError::Parse(e) => write!(f, "could not parse scenario file: {e}"),
+            ScenarioError::Invalid(reason) => write!(f, "invalid scenario: {reason}"),
+            ScenarioError::DragonThreshold(threshold) => write!(
+                f,
+                "invalid scenario: the dragon only attacks with {threshold} dragon dice, the \
+                 dragon_threshold of the rules"
+            ),
+        }
+    }
+}
+
+impl Error for ScenarioError {}
+
+impl From<io::Error> for ScenarioError {
+    fn from(e: io::Error) -> Self {
+        ScenarioError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ScenarioError {
+    fn from(e: toml::de::Error) -> Self {
+        ScenarioError::Parse(e)
+    }
+}
+
+/// The decision a scenario starts on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Start {
+    Monster,
+    Loot,
+    Dragon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Goal {
+    ClearLevel { max_allies: Option<u64> },
+    SlayDragon { max_allies: Option<u64> },
+    ReachLevel { level: u64 },
+}
+
+impl Goal {
+    pub fn describe(self, language: Language) -> String {
+        let goal = match self {
+            Goal::ClearLevel { .. } => language.text("goal.clear_level").into_owned(),
+            Goal::SlayDragon { .. } => language.text("goal.slay_dragon").into_owned(),
+            Goal::ReachLevel { level } => {
+                language.format("goal.reach_level", &[("level", level.to_string())])
+            }
+        };
+
+        match self.max_allies() {
+            Some(1) => language.format("goal.limit_one", &[("goal", goal)]),
+            Some(n) => language.format("goal.limit", &[("goal", goal), ("allies", n.to_string())]),
+            None => goal,
+        }
+    }
+
+    pub fn max_allies(self) -> Option<u64> {
+        match self {
+            Goal::ClearLevel { max_allies } | Goal::SlayDragon { max_allies } => max_allies,
+            Goal::ReachLevel { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalStatus {
+    Pending,
+    Solved,
+    Failed,
+}
+
+/// A hand-authored dungeon state to play from
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    #[serde(default = "default_hero")]
+    pub hero: Type,
+    #[serde(default = "first")]
+    pub delve: u64,
+    #[serde(default = "first")]
+    pub level: u64,
+    pub phase: Start,
+    pub party: Vec<Ally>,
+    pub dungeon: Vec<Monster>,
+    #[serde(default)]
+    pub graveyard: Vec<Ally>,
+    #[serde(default)]
+    pub inventory: Vec<Treasure>,
+    pub goal: Goal,
+}
+
+fn default_hero() -> Type {
+    Type::ALL[0]
+}
+
+fn first() -> u64 {
+    1
+}
+
+impl Scenario {
+    pub fn load<P: AsRef<Path>>(path: P, rules: &Rules) -> Result<Self, ScenarioError> {
+        let scenario: Scenario = toml::from_str(&fs::read_to_string(path)?)?;
+        scenario.validate(rules)?;
+
+        Ok(scenario)
+    }
+
+    pub fn validate(&self, rules: &Rules) -> Result<(), ScenarioError> {
+        if self.delve == 0 || self.level == 0 {
+            return Err(ScenarioError::Invalid("delve and level start at 1"));
+        }
+
+        if self.party.is_empty() {
+            return Err(ScenarioError::Invalid("the party can't be empty"));
+        }
+
+        let ok = match self.phase {
+            Start::Monster => self.dungeon.iter().any(Monster::is_monster),
+            Start::Loot => self.dungeon.iter().any(Monster::is_loot),
+            Start::Dragon => self.dungeon.contains(&Monster::Dragon),
+        };
+        if !ok {
+            return Err(ScenarioError::Invalid(
+                "the dungeon has nothing to do in the starting phase",
+            ));
+        }
+
+        let dragons = self
+            .dungeon
+            .iter()
+            .filter(|m| **m == Monster::Dragon)
+            .count();
+        if self.phase == Start::Dragon && (dragons as u64) < rules.dragon_threshold {
+            return Err(ScenarioError::DragonThreshold(rules.dragon_threshold));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    dice_source::DiceSource,
+    game::{Game, HELP},
+    saves::Saves,
+};
+use std::io::{self, BufRead, Write};
+
+/// Plays by reading commands from stdin and printing the state after each
+/// one, errors are reported as `error: ...` lines
+pub fn run<R: DiceSource>(game: Game<R>, saves: &Saves) -> io::Result<()> {
+    play(game, io::stdin().lock(), io::stdout(), saves)
+}
+
+/// Like `run`, reading commands from `input` and writing to `stdout`
+pub fn play<R: DiceSource>(
+    mut game: Game<R>,
+    input: impl BufRead,
+    mut stdout: impl Write,
+    saves: &Saves,
+) -> io::Result<()> {
+    write!(stdout, "{}", game.describe())?;
+    stdout.flush()?;
+
+    for line in input.lines() {
+        let line = line?;
+        match line.trim() {
+            "quit" => break,
+            "help" => writeln!(stdout, "{HELP}")?,
+            command => {
+                if let Err(e) = game.execute(command) {
+                    writeln!(stdout, "error: {e}")?;
+                }
+                write!(stdout, "{}", game.describe())?;
+            }
+        }
+        stdout.flush()?;
+
+        saves.store(&mut game)?;
+    }
+
+    Ok(())
+}
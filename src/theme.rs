@@ -0,0 +1,141 @@
+use crate::dice::{Ally, Monster};
+use serde::Deserialize;
+use tui::style::{Color, Modifier, Style};
+
+// Okabe-Ito palette, told apart with the common kinds of color blindness
+const ORANGE: Color = Color::Rgb(230, 159, 0);
+const SKY_BLUE: Color = Color::Rgb(86, 180, 233);
+const BLUISH_GREEN: Color = Color::Rgb(0, 158, 115);
+const YELLOW: Color = Color::Rgb(240, 228, 66);
+const BLUE: Color = Color::Rgb(0, 114, 178);
+const VERMILLION: Color = Color::Rgb(213, 94, 0);
+const REDDISH_PURPLE: Color = Color::Rgb(204, 121, 167);
+
+/// How dice are colored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// Every ally shares its color with the monster it's best against
+    #[default]
+    Default,
+    /// No two dice share a style, nor a monster the color of an ally
+    Colorblind,
+    /// No colors at all, every face has modifiers of its own
+    Monochrome,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Default, Theme::Colorblind, Theme::Monochrome];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Default => "default",
+            Theme::Colorblind => "colorblind",
+            Theme::Monochrome => "monochrome",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.name() == name)
+    }
+
+    pub fn ally(self, ally: &Ally) -> Style {
+        let style = Style::default();
+        match self {
+            Theme::Default => match ally {
+                Ally::Fighter => style.fg(Color::Green),
+                Ally::Cleric => style.fg(Color::DarkGray),
+                Ally::Mage => style.fg(Color::Blue),
+                Ally::Thief => style.fg(Color::Rgb(128, 0, 128)),
+                Ally::Champion => style.fg(Color::Yellow),
+                Ally::Scroll => style.fg(Color::Rgb(255, 165, 0)),
+            },
+            Theme::Colorblind => match ally {
+                Ally::Fighter => style.fg(SKY_BLUE),
+                Ally::Cleric => style.fg(YELLOW),
+                Ally::Mage => style.fg(BLUISH_GREEN),
+                Ally::Thief => style.fg(REDDISH_PURPLE),
+                Ally::Champion => style.fg(ORANGE),
+                Ally::Scroll => style.fg(VERMILLION),
+            },
+            Theme::Monochrome => style.add_modifier(match ally {
+                Ally::Fighter => Modifier::BOLD,
+                Ally::Cleric => Modifier::UNDERLINED,
+                Ally::Mage => Modifier::ITALIC | Modifier::UNDERLINED,
+                Ally::Thief => Modifier::BOLD | Modifier::ITALIC,
+                Ally::Champion => Modifier::BOLD | Modifier::UNDERLINED,
+                Ally::Scroll => Modifier::ITALIC,
+            }),
+        }
+    }
+
+    pub fn monster(self, monster: &Monster) -> Style {
+        let style = Style::default();
+        match self {
+            Theme::Default => match monster {
+                Monster::Goblin => style.fg(Color::Green),
+                Monster::Skeleton => style.fg(Color::DarkGray),
+                Monster::Ooze => style.fg(Color::Blue),
+                Monster::Dragon => style.fg(Color::Red),
+                Monster::Chest => style.fg(Color::Rgb(128, 0, 128)),
+                Monster::Potion => style.fg(Color::Rgb(255, 165, 0)),
+            },
+            // the allies take every other color of the palette, so monsters
+            // to fight are blue and the rest gray, told apart by modifiers
+            Theme::Colorblind => match monster {
+                Monster::Goblin => style.fg(BLUE),
+                Monster::Skeleton => style.fg(BLUE).add_modifier(Modifier::ITALIC),
+                Monster::Ooze => style.fg(BLUE).add_modifier(Modifier::UNDERLINED),
+                Monster::Dragon => style
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                Monster::Chest => style.fg(Color::Gray),
+                Monster::Potion => style.fg(Color::Gray).add_modifier(Modifier::ITALIC),
+            },
+            // struck through, which no ally is
+            Theme::Monochrome => style.add_modifier(
+                Modifier::CROSSED_OUT
+                    | match monster {
+                        Monster::Goblin => Modifier::empty(),
+                        Monster::Skeleton => Modifier::ITALIC,
+                        Monster::Ooze => Modifier::UNDERLINED,
+                        Monster::Dragon => Modifier::BOLD | Modifier::UNDERLINED,
+                        Monster::Chest => Modifier::BOLD,
+                        Monster::Potion => Modifier::BOLD | Modifier::ITALIC,
+                    },
+            ),
+        }
+    }
+
+    /// The die under the cursor, shown every other blink
+    pub fn cursor(self) -> Style {
+        match self {
+            Theme::Default | Theme::Colorblind => Style::default().bg(Color::White),
+            // reversed already marks the selection, so the cursor blinks the
+            // die out of sight instead
+            Theme::Monochrome => Style::default().add_modifier(Modifier::HIDDEN),
+        }
+    }
+
+    /// Dice picked for the move being made
+    pub fn selected(self) -> Style {
+        match self {
+            Theme::Default | Theme::Colorblind => Style::default().bg(Color::DarkGray),
+            // no ally is reversed, so the mark never blends into one
+            Theme::Monochrome => Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Dice picked for a move that is waiting to be confirmed
+    pub fn confirmed(self) -> Style {
+        self.selected().add_modifier(Modifier::DIM)
+    }
+
+    /// Every other die
+    pub fn idle(self) -> Style {
+        match self {
+            Theme::Default | Theme::Colorblind => Style::default().bg(Color::Black),
+            Theme::Monochrome => Style::default(),
+        }
+    }
+}
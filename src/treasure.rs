@@ -1,6 +1,8 @@
-use std::collections::HashMap;
+use crate::locale::Language;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub enum Treasure {
     VorpalSword,
     Talisman,
@@ -14,23 +16,45 @@ pub enum Treasure {
     TownPortal,
 }
 
+impl Treasure {
+    pub const ALL: [Treasure; 10] = [
+        Treasure::VorpalSword,
+        Treasure::Talisman,
+        Treasure::ScepterOfPower,
+        Treasure::ThievesTools,
+        Treasure::Scroll,
+        Treasure::RingOfInvisiblity,
+        Treasure::DragonScales,
+        Treasure::Potion,
+        Treasure::DragonBait,
+        Treasure::TownPortal,
+    ];
+
+    pub fn name(&self, language: Language) -> Cow<'static, str> {
+        language.text(match self {
+            Treasure::VorpalSword => "treasure.vorpal_sword",
+            Treasure::Talisman => "treasure.talisman",
+            Treasure::ScepterOfPower => "treasure.scepter_of_power",
+            Treasure::ThievesTools => "treasure.thieves_tools",
+            Treasure::Scroll => "treasure.scroll",
+            Treasure::RingOfInvisiblity => "treasure.ring_of_invisibility",
+            Treasure::DragonScales => "treasure.dragon_scales",
+            Treasure::Potion => "treasure.potion",
+            Treasure::DragonBait => "treasure.dragon_bait",
+            Treasure::TownPortal => "treasure.town_portal",
+        })
+    }
+}
+
 lazy_static! {
     pub static ref TREASURE: Vec<Treasure> = {
-        let mut amounts = HashMap::new();
-        amounts.insert(Treasure::VorpalSword, 3);
-        amounts.insert(Treasure::Talisman, 3);
-        amounts.insert(Treasure::ScepterOfPower, 3);
-        amounts.insert(Treasure::ThievesTools, 3);
-        amounts.insert(Treasure::Scroll, 3);
-        amounts.insert(Treasure::RingOfInvisiblity, 4);
-        amounts.insert(Treasure::DragonScales, 6);
-        amounts.insert(Treasure::Potion, 3);
-        amounts.insert(Treasure::DragonBait, 4);
-        amounts.insert(Treasure::TownPortal, 4);
+        // how many of each treasure the bag holds, in the order of `ALL` so
+        // that the same seed always draws the same treasure
+        const AMOUNTS: [usize; 10] = [3, 3, 3, 3, 3, 4, 6, 3, 4, 4];
 
-        let mut treasure = Vec::with_capacity(36);
-        for (item, amt) in &amounts {
-            (0..*amt).for_each(|_| {
+        let mut treasure = Vec::with_capacity(AMOUNTS.iter().sum());
+        for (item, amt) in Treasure::ALL.iter().zip(AMOUNTS) {
+            (0..amt).for_each(|_| {
                 treasure.push(item.clone());
             });
         }
@@ -0,0 +1,237 @@
+mod common;
+
+use common::{dice, dragon_rules, hero, play, start};
+use dungeon_roll::{
+    achievements::{Achievement, Achievements},
+    dice::{Ally, Monster},
+    dice_source::ScriptedDice,
+    game::{Command, Game},
+    phase::{Phase, Regroup},
+    roster::Roster,
+    rules::{Mode, Rules},
+};
+
+/// A classic delve of seven `allies`, tracking achievements, with the
+/// dungeon rolling `monsters` level after level
+fn game(
+    allies: impl IntoIterator<Item = Ally>,
+    monsters: Vec<Monster>,
+    rules: Rules,
+) -> Game<ScriptedDice> {
+    let roster = Roster::from(vec![hero(Mode::Classic)]);
+    let mut game = start(dice(allies, monsters), roster, rules);
+    game.track_achievements(Achievements::default());
+    game
+}
+
+/// Every achievement unlocked, if one was since the last call
+fn unlocked(game: &mut Game<ScriptedDice>) -> Vec<Achievement> {
+    game.take_achievements().map_or(vec![], |achievements| {
+        Achievement::ALL
+            .into_iter()
+            .filter(|a| achievements.is_unlocked(*a))
+            .collect()
+    })
+}
+
+const FIGHT: Command = Command::Fight {
+    ally: 0,
+    monster: 0,
+};
+
+#[test]
+fn slaying_the_dragon_without_champions() {
+    let mut game = game(
+        vec![Ally::Fighter, Ally::Cleric, Ally::Mage, Ally::Thief],
+        vec![Monster::Goblin, Monster::Dragon],
+        dragon_rules(),
+    );
+    play(
+        &mut game,
+        &[
+            FIGHT,
+            Command::Dragon {
+                allies: vec![0, 1, 2],
+            },
+        ],
+    );
+    assert_eq!(
+        unlocked(&mut game),
+        [Achievement::DragonSlayer, Achievement::NoChampions]
+    );
+}
+
+#[test]
+fn slaying_the_dragon_with_a_champion() {
+    let mut game = game(
+        vec![Ally::Fighter, Ally::Champion, Ally::Cleric, Ally::Mage],
+        vec![Monster::Goblin, Monster::Dragon],
+        dragon_rules(),
+    );
+    play(
+        &mut game,
+        &[
+            FIGHT,
+            Command::Dragon {
+                allies: vec![0, 1, 2],
+            },
+        ],
+    );
+    assert_eq!(unlocked(&mut game), [Achievement::DragonSlayer]);
+}
+
+#[test]
+fn clearing_the_tenth_level() {
+    let mut game = game(vec![], vec![Monster::Chest; 100], Rules::default());
+    for level in 1..=10 {
+        assert_eq!(game.observe().level, level);
+        play(&mut game, &[Command::Skip]);
+        assert_eq!(*game.phase(), Phase::Regroup(Regroup::Continue));
+        if level < 10 {
+            assert!(unlocked(&mut game).is_empty());
+            play(&mut game, &[Command::Continue]);
+        }
+    }
+    assert_eq!(unlocked(&mut game), [Achievement::DeepDelver]);
+}
+
+#[test]
+fn reviving_three_allies_at_once() {
+    let mut game = game(
+        vec![Ally::Cleric, Ally::Mage, Ally::Thief, Ally::Fighter],
+        vec![
+            // level 1
+            Monster::Goblin,
+            Monster::Goblin,
+            // level 2
+            Monster::Goblin,
+            Monster::Goblin,
+            Monster::Goblin,
+            // level 3
+            Monster::Potion,
+            Monster::Potion,
+            Monster::Potion,
+            Monster::Chest,
+        ],
+        Rules::default(),
+    );
+    play(
+        &mut game,
+        &[
+            FIGHT,
+            FIGHT,
+            Command::Continue,
+            FIGHT,
+            FIGHT,
+            Command::Continue,
+        ],
+    );
+    assert_eq!(game.observe().graveyard.len(), 4);
+    assert!(unlocked(&mut game).is_empty());
+
+    play(
+        &mut game,
+        &[Command::Quaff {
+            ally: 0,
+            graves: Some(vec![0, 1, 2]),
+        }],
+    );
+    assert_eq!(unlocked(&mut game), [Achievement::MiracleWorker]);
+}
+
+#[test]
+fn retiring_with_nobody_left() {
+    // a party of three, which only a campaign can be capped to
+    let dice = dice(
+        [Ally::Fighter, Ally::Mage, Ally::Thief],
+        [
+            Monster::Goblin,
+            Monster::Ooze,
+            Monster::Chest,
+            Monster::Chest,
+            Monster::Chest,
+        ],
+    );
+    let rules = Rules {
+        max_party_size: 3,
+        ..Rules::default()
+    };
+    let mut game = start(dice, Roster::from(vec![hero(Mode::Campaign)]), rules);
+    game.track_achievements(Achievements::default());
+    play(
+        &mut game,
+        &[
+            FIGHT,
+            FIGHT,
+            Command::Continue,
+            Command::Open { ally: 0, chest: 0 },
+        ],
+    );
+    assert!(game.observe().party.is_empty());
+    assert!(unlocked(&mut game).is_empty());
+
+    play(&mut game, &[Command::Retire]);
+    assert_eq!(unlocked(&mut game), [Achievement::LastOneOut]);
+}
+
+#[test]
+fn hoarding_five_treasures() {
+    let mut game = game(
+        (0..7).map(|_| Ally::Thief),
+        vec![Monster::Chest; 5],
+        Rules::default(),
+    );
+    play(
+        &mut game,
+        &[Command::Open { ally: 0, chest: 0 }, Command::Continue],
+    );
+    assert_eq!(game.observe().inventory.len(), 2);
+    assert!(unlocked(&mut game).is_empty());
+
+    play(&mut game, &[Command::Open { ally: 0, chest: 0 }]);
+    assert_eq!(game.observe().inventory.len(), 5);
+    assert_eq!(unlocked(&mut game), [Achievement::Hoarder]);
+}
+
+#[test]
+fn achievements_unlock_once() {
+    let mut game = game(
+        (0..7).map(|_| Ally::Thief),
+        vec![Monster::Chest; 5],
+        Rules::default(),
+    );
+    play(
+        &mut game,
+        &[Command::Open { ally: 0, chest: 0 }, Command::Continue],
+    );
+    play(&mut game, &[Command::Open { ally: 0, chest: 0 }]);
+    assert_eq!(unlocked(&mut game), [Achievement::Hoarder]);
+    assert!(unlocked(&mut game).is_empty());
+}
+
+#[test]
+fn toasts_last_six_blinks_each() {
+    let mut game = game(
+        vec![Ally::Fighter, Ally::Cleric, Ally::Mage, Ally::Thief],
+        vec![Monster::Goblin, Monster::Dragon],
+        dragon_rules(),
+    );
+    assert_eq!(game.toast(), None);
+    play(
+        &mut game,
+        &[
+            FIGHT,
+            Command::Dragon {
+                allies: vec![0, 1, 2],
+            },
+        ],
+    );
+
+    for toast in [Achievement::DragonSlayer, Achievement::NoChampions] {
+        for _ in 0..6 {
+            assert_eq!(game.toast(), Some(toast));
+            game.toggle_blink();
+        }
+    }
+    assert_eq!(game.toast(), None);
+}
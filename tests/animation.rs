@@ -0,0 +1,50 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use dungeon_roll::{
+    game::{Game, ROLL_FRAMES},
+    phase::{Monster, Phase},
+    roster::Roster,
+    rules::Rules,
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+
+fn press(game: &mut Game<Pcg64Mcg>, code: KeyCode) {
+    game.handle_event(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+}
+
+/// A delve whose first dice are still rolling
+fn rolling() -> Game<Pcg64Mcg> {
+    let mut game = Game::new(
+        Pcg64Mcg::seed_from_u64(0),
+        Roster::default(),
+        Rules::default(),
+    );
+    game.set_animations(true);
+    press(&mut game, KeyCode::Enter);
+
+    game
+}
+
+#[test]
+fn dice_settle_after_the_roll() {
+    let mut game = rolling();
+
+    for _ in 1..ROLL_FRAMES {
+        game.tick();
+    }
+    assert!(game.is_rolling());
+
+    game.tick();
+    assert!(!game.is_rolling());
+}
+
+#[test]
+fn confirm_skips_the_roll() {
+    let mut game = rolling();
+    assert!(game.is_rolling());
+
+    // only skipping, not playing the die under the cursor
+    press(&mut game, KeyCode::Enter);
+    assert!(!game.is_rolling());
+    assert_eq!(*game.phase(), Phase::Monster(Monster::SelectAlly));
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use common::{dice, hero};
+use dungeon_roll::{
+    game::{Command, Game},
+    hero::{Hero, Type},
+    phase::Phase,
+    roster::Roster,
+    rules::{Mode, Rules, CLASSIC_DELVES},
+};
+
+#[test]
+fn runs_start_from_the_first_delve() {
+    let mut classic = hero(Mode::Classic);
+    let mut campaign = Hero::new("Bo".to_string(), Type::Paladin);
+    for _ in 0..CLASSIC_DELVES {
+        classic.finish_delve(1);
+        campaign.finish_delve(1);
+    }
+    assert!(classic.is_finished());
+
+    for index in 0..2 {
+        let roster = Roster::from(vec![classic.clone(), campaign.clone()]);
+        let mut game = Game::new(dice([], []), roster, Rules::default());
+        game.perform(&Command::Hero { index }).unwrap();
+        game.perform(&Command::Start).unwrap();
+
+        assert_ne!(*game.phase(), Phase::GameOver);
+        assert_eq!(game.observe().delve, 1);
+        assert_eq!(game.observe().roster[index].delves(), CLASSIC_DELVES);
+    }
+}
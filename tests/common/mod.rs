@@ -0,0 +1,63 @@
+//! Fixtures shared by the integration tests, each test file uses some of them
+#![allow(dead_code)]
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use dungeon_roll::{
+    dice::{Ally, Monster},
+    dice_source::ScriptedDice,
+    game::{Command, Game},
+    hero::{Hero, Type},
+    roster::Roster,
+    rules::{Mode, Rules},
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+
+/// Dice that roll `allies` for the party and `monsters` for the dungeon, and
+/// the rolls of seed 0 once those run out
+pub fn dice(
+    allies: impl IntoIterator<Item = Ally>,
+    monsters: impl IntoIterator<Item = Monster>,
+) -> ScriptedDice {
+    let mut dice = ScriptedDice::new(Pcg64Mcg::seed_from_u64(0));
+    dice.queue(allies);
+    dice.queue(monsters);
+    dice
+}
+
+/// The default rules, with the dragon attacking as soon as it's rolled
+pub fn dragon_rules() -> Rules {
+    Rules {
+        dragon_threshold: 1,
+        ..Rules::default()
+    }
+}
+
+/// A new hero playing `mode`
+pub fn hero(mode: Mode) -> Hero {
+    let mut hero = Hero::new("Lyra".to_string(), Type::Bard);
+    hero.set_mode(mode);
+    hero
+}
+
+/// A game of `roster` that has started its first delve
+pub fn start(dice: ScriptedDice, roster: Roster, rules: Rules) -> Game<ScriptedDice> {
+    let mut game = Game::new(dice, roster, rules);
+    game.perform(&Command::Start).unwrap();
+    game
+}
+
+pub fn play(game: &mut Game<ScriptedDice>, commands: &[Command]) {
+    for command in commands {
+        game.perform(command).unwrap();
+    }
+}
+
+/// The tutorial, rolling like seed 0 whatever it doesn't script
+pub fn tutorial() -> Game<ScriptedDice> {
+    Game::tutorial(ScriptedDice::new(Pcg64Mcg::seed_from_u64(0)))
+}
+
+pub fn press(game: &mut Game<ScriptedDice>, code: KeyCode) {
+    game.handle_event(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+}
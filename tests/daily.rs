@@ -0,0 +1,89 @@
+mod common;
+
+use common::dice;
+use dungeon_roll::{
+    daily::{Daily, Records, Run},
+    dice::{Ally, Monster},
+    game::{Command, Game},
+    hero::Type,
+    phase::Phase,
+};
+
+#[test]
+fn dates_come_from_the_day() {
+    for (days, date, seed) in [
+        (0, "1970-01-01", 19_700_101),
+        (11_016, "2000-02-29", 20_000_229),
+        (18_321, "2020-02-29", 20_200_229),
+        (18_322, "2020-03-01", 20_200_301),
+        (18_992, "2021-12-31", 20_211_231),
+        (18_993, "2022-01-01", 20_220_101),
+    ] {
+        let daily = Daily::from_days(days);
+        assert_eq!(daily.date, date);
+        assert_eq!(daily.seed, seed);
+    }
+}
+
+#[test]
+fn one_attempt_per_day() {
+    let today = Daily::from_days(18_993);
+    let run = |score| Run {
+        date: today.date.clone(),
+        seed: today.seed,
+        hero: Type::Bard,
+        score,
+    };
+
+    let mut records = Records::default();
+    assert_eq!(records.find(&today.date), None);
+
+    // the attempt is updated as it goes, and found when trying again
+    records.record(run(0));
+    records.record(run(4));
+    assert_eq!(records.find(&today.date), Some(&run(4)));
+
+    let tomorrow = Daily::from_days(18_994);
+    assert_eq!(records.find(&tomorrow.date), None);
+}
+
+#[test]
+fn slaying_the_dragon_scores() {
+    let daily = Daily {
+        date: "2022-01-01".to_string(),
+        seed: 20_220_101,
+    };
+    // two dragons set aside on the first level, the third wakes them up
+    let dice = dice(
+        [Ally::Fighter, Ally::Cleric, Ally::Mage, Ally::Thief],
+        [
+            Monster::Dragon,
+            Monster::Dragon,
+            Monster::Dragon,
+            Monster::Goblin,
+            Monster::Goblin,
+        ],
+    );
+
+    let mut game = Game::with_daily(dice, daily.clone());
+    let mut records = Records::default();
+    for command in [
+        Command::Start,
+        Command::Next,
+        Command::Continue,
+        Command::Fight {
+            ally: 0,
+            monster: 3,
+        },
+        Command::Dragon {
+            allies: vec![0, 1, 2],
+        },
+    ] {
+        game.perform(&command).unwrap();
+        records.record(game.daily_run().unwrap());
+    }
+    assert_eq!(*game.phase(), Phase::Victory);
+
+    let run = records.find(&daily.date).unwrap();
+    assert_eq!(run.score, 3);
+}
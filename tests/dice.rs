@@ -0,0 +1,49 @@
+mod common;
+
+use common::{dice, dragon_rules, start};
+use dungeon_roll::{
+    dice::{Ally, Monster},
+    game::Command,
+    phase::{Dragon, Phase},
+    roster::Roster,
+    rules::Rules,
+};
+
+#[test]
+fn empty_dungeon() {
+    let rules = Rules {
+        dragon_threshold: 3,
+        ..Rules::default()
+    };
+    let game = start(
+        dice([], [Monster::Dragon, Monster::Dragon]),
+        Roster::default(),
+        rules,
+    );
+    assert_eq!(*game.phase(), Phase::EmptyDungeon);
+}
+
+#[test]
+fn dragon_fight() {
+    let mut game = start(
+        dice(
+            [Ally::Fighter, Ally::Cleric, Ally::Mage, Ally::Thief],
+            [Monster::Goblin, Monster::Dragon],
+        ),
+        Roster::default(),
+        dragon_rules(),
+    );
+    game.perform(&Command::Fight {
+        ally: 0,
+        monster: 0,
+    })
+    .unwrap();
+    assert_eq!(*game.phase(), Phase::Dragon(Dragon::SelectAlly));
+
+    game.perform(&Command::Dragon {
+        allies: vec![0, 1, 2],
+    })
+    .unwrap();
+    assert_eq!(*game.phase(), Phase::Victory);
+    assert!(game.observe().party.is_empty());
+}
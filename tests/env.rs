@@ -0,0 +1,91 @@
+use dungeon_roll::{
+    env::{BatchEnv, Env, Observation, ILLEGAL_REWARD},
+    game::Command,
+    phase::Phase,
+    rules::Rules,
+};
+
+/// The last legal action, which retires at the first Regroup and skips
+/// every loot, with the first three companions against the dragon
+fn policy(env: &Env) -> Option<Command> {
+    match env.legal_actions().pop()? {
+        Command::Dragon { allies } => Some(Command::Dragon {
+            allies: allies.into_iter().take(3).collect(),
+        }),
+        action => Some(action),
+    }
+}
+
+/// Plays `policy` until the episode ends, returning every observation and
+/// the total reward
+fn episode(env: &mut Env, seed: u64) -> (Vec<Vec<f32>>, f32) {
+    let mut observations = vec![env.reset(seed).to_vec()];
+    let mut total = 0.;
+    while let Some(action) = policy(&env) {
+        let (observation, reward, done) = env.step(&action);
+        assert!(reward > ILLEGAL_REWARD, "{action:?} was refused");
+        observations.push(observation.to_vec());
+        total += reward;
+        if done {
+            break;
+        }
+    }
+
+    (observations, total)
+}
+
+#[test]
+fn same_seed_same_observations() {
+    for seed in 0..8 {
+        let mut env = Env::new(Rules::default());
+        assert_eq!(
+            episode(&mut env, seed),
+            episode(&mut env, seed),
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn slaying_the_dragon_ends_the_episode() {
+    let mut env = Env::new(Rules {
+        dragon_threshold: 1,
+        ..Rules::default()
+    });
+    for seed in [5, 6, 45] {
+        let (_, reward) = episode(&mut env, seed);
+        assert_eq!(*env.game().phase(), Phase::Victory, "seed {seed}");
+        assert!(reward > 0., "seed {seed}");
+        assert!(env.legal_actions().is_empty());
+    }
+}
+
+#[test]
+fn batches_step_like_single_environments() {
+    // enough environments to be split across threads
+    let seeds = (0..200).collect::<Vec<u64>>();
+    let mut batch = BatchEnv::new(&Rules::default(), seeds.len());
+    let mut envs = seeds
+        .iter()
+        .map(|_| Env::new(Rules::default()))
+        .collect::<Vec<_>>();
+
+    let observations = batch.reset(&seeds);
+    for ((env, seed), observation) in envs.iter_mut().zip(&seeds).zip(observations) {
+        assert_eq!(env.reset(*seed), observation);
+    }
+
+    while envs.iter().any(|env| !env.legal_actions().is_empty()) {
+        // finished environments get a command they ignore
+        let actions = envs
+            .iter()
+            .map(|env| policy(env).unwrap_or(Command::Start))
+            .collect::<Vec<_>>();
+        let stepped = envs
+            .iter_mut()
+            .zip(&actions)
+            .map(|(env, action)| env.step(action))
+            .collect::<Vec<(Observation, f32, bool)>>();
+        assert_eq!(batch.step(&actions), stepped);
+    }
+}
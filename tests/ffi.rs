@@ -0,0 +1,131 @@
+use std::{
+    ffi::{CStr, CString},
+    ptr,
+};
+
+use dungeon_roll::{
+    env::Env,
+    ffi::{
+        dr_game_free, dr_game_new, dr_game_observation, dr_game_reset, dr_game_state, dr_game_step,
+        dr_observation_size, dr_string_free, DR_BUFFER_TOO_SMALL, DR_ILLEGAL, DR_INVALID_UTF8,
+        DR_MALFORMED, DR_NULL, DR_OK,
+    },
+    game::Command,
+};
+use serde_json::Value;
+
+/// The JSON state of `game`, freeing the returned string
+unsafe fn state(game: *mut Env) -> Value {
+    let s = dr_game_state(game);
+    assert!(!s.is_null());
+    let state = serde_json::from_str(CStr::from_ptr(s).to_str().unwrap()).unwrap();
+    dr_string_free(s);
+    state
+}
+
+#[test]
+fn plays_through_the_c_api() {
+    unsafe {
+        let game = dr_game_new(7);
+        assert!(!game.is_null());
+
+        let mut observation = vec![0.; dr_observation_size()];
+        let len = observation.len();
+        assert_eq!(
+            dr_game_observation(game, observation.as_mut_ptr(), len),
+            DR_OK
+        );
+        assert!(observation.iter().any(|&x| x != 0.));
+        assert_eq!(
+            dr_game_observation(game, observation.as_mut_ptr(), len - 1),
+            DR_BUFFER_TOO_SMALL
+        );
+
+        let state = state(game);
+        assert!(state["observation"].is_object());
+        let legal = state["legal"][0].to_string();
+        let command = CString::new(legal).unwrap();
+        let mut reward = f32::NAN;
+        let mut done = true;
+        assert_eq!(
+            dr_game_step(game, command.as_ptr(), &mut reward, &mut done),
+            DR_OK
+        );
+        assert!(!reward.is_nan());
+        assert!(!done);
+
+        assert_eq!(dr_game_reset(game, 8), DR_OK);
+        dr_game_free(game);
+    }
+}
+
+#[test]
+fn bad_commands_are_refused() {
+    unsafe {
+        let game = dr_game_new(7);
+        let mut reward = 0.;
+        let mut done = false;
+
+        let malformed = CString::new("{\"Fight\":").unwrap();
+        assert_eq!(
+            dr_game_step(game, malformed.as_ptr(), &mut reward, &mut done),
+            DR_MALFORMED
+        );
+
+        let next = CString::new(serde_json::to_string(&Command::Next).unwrap()).unwrap();
+        assert_eq!(
+            dr_game_step(game, next.as_ptr(), &mut reward, &mut done),
+            DR_ILLEGAL
+        );
+
+        let invalid = [0xff_u8, 0xfe, 0];
+        assert_eq!(
+            dr_game_step(game, invalid.as_ptr().cast(), &mut reward, &mut done),
+            DR_INVALID_UTF8
+        );
+
+        // the out parameters are optional
+        let legal = CString::new(state(game)["legal"][0].to_string()).unwrap();
+        assert_eq!(
+            dr_game_step(game, legal.as_ptr(), ptr::null_mut(), ptr::null_mut()),
+            DR_OK
+        );
+
+        dr_game_free(game);
+    }
+}
+
+#[test]
+fn null_pointers_are_refused() {
+    unsafe {
+        let game = dr_game_new(7);
+        let command = CString::new(serde_json::to_string(&Command::Next).unwrap()).unwrap();
+        let mut observation = vec![0.; dr_observation_size()];
+        let len = observation.len();
+
+        assert_eq!(
+            dr_game_step(
+                ptr::null_mut(),
+                command.as_ptr(),
+                ptr::null_mut(),
+                ptr::null_mut()
+            ),
+            DR_NULL
+        );
+        assert_eq!(
+            dr_game_step(game, ptr::null(), ptr::null_mut(), ptr::null_mut()),
+            DR_NULL
+        );
+        assert_eq!(
+            dr_game_observation(ptr::null(), observation.as_mut_ptr(), len),
+            DR_NULL
+        );
+        assert_eq!(dr_game_observation(game, ptr::null_mut(), len), DR_NULL);
+        assert_eq!(dr_game_reset(ptr::null_mut(), 7), DR_NULL);
+        assert!(dr_game_state(ptr::null()).is_null());
+
+        dr_game_free(ptr::null_mut());
+        dr_string_free(ptr::null_mut());
+        dr_game_free(game);
+    }
+}
@@ -0,0 +1,79 @@
+use dungeon_roll::{
+    hero::Type,
+    history::{Ending, History, Run, Sort},
+};
+
+fn run(hero: &str, hero_type: Type, level: u64, xp: u64) -> Run {
+    Run {
+        hero: hero.to_string(),
+        hero_type,
+        seed: 0,
+        delve: 1,
+        level,
+        xp,
+        treasure: 0,
+        ending: Ending::Retired,
+    }
+}
+
+/// Oldest first, as they're pushed at the end of each delve
+fn fixture() -> History {
+    let mut history = History::default();
+    for run in [
+        run("old", Type::Bard, 5, 10),
+        run("deep", Type::Paladin, 9, 8),
+        run("best", Type::Bard, 6, 12),
+        run("shallow", Type::Paladin, 3, 10),
+        run("new", Type::Bard, 5, 10),
+    ] {
+        history.push(run);
+    }
+    history
+}
+
+fn heroes(runs: &[&Run]) -> Vec<String> {
+    runs.iter().map(|r| r.hero.clone()).collect()
+}
+
+#[test]
+fn score_sorts_by_xp_then_level_then_most_recent() {
+    let history = fixture();
+    assert_eq!(
+        heroes(&history.leaderboard(Sort::Score, None)),
+        ["best", "new", "old", "shallow", "deep"]
+    );
+}
+
+#[test]
+fn depth_sorts_by_level_then_xp_then_most_recent() {
+    let history = fixture();
+    assert_eq!(
+        heroes(&history.leaderboard(Sort::Depth, None)),
+        ["deep", "best", "new", "old", "shallow"]
+    );
+}
+
+#[test]
+fn recent_sorts_newest_first() {
+    let history = fixture();
+    assert_eq!(
+        heroes(&history.leaderboard(Sort::Recent, None)),
+        ["new", "shallow", "best", "deep", "old"]
+    );
+}
+
+#[test]
+fn filter_keeps_one_hero_type() {
+    let history = fixture();
+    assert_eq!(
+        heroes(&history.leaderboard(Sort::Score, Some(Type::Paladin))),
+        ["shallow", "deep"]
+    );
+    assert_eq!(
+        heroes(&history.leaderboard(Sort::Recent, Some(Type::Bard))),
+        ["new", "best", "old"]
+    );
+    assert!(history
+        .leaderboard(Sort::Score, Some(Type::Necromancer))
+        .is_empty());
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::{dice, dragon_rules, play};
+use dungeon_roll::{
+    dice::{Ally, Monster},
+    game::{Command, Game},
+    phase::Phase,
+    roster::Roster,
+};
+
+#[test]
+fn victory_passes_the_turn() {
+    let dice = dice(
+        [Ally::Fighter, Ally::Cleric, Ally::Mage, Ally::Thief],
+        [Monster::Goblin, Monster::Dragon],
+    );
+    let mut game = Game::new(dice, Roster::default(), dragon_rules());
+    play(
+        &mut game,
+        &[
+            Command::Player,
+            Command::Hero { index: 1 },
+            Command::Player,
+            Command::Start,
+            Command::Fight {
+                ally: 0,
+                monster: 0,
+            },
+            Command::Dragon {
+                allies: vec![0, 1, 2],
+            },
+        ],
+    );
+    assert_eq!(*game.phase(), Phase::Victory);
+
+    // the winner banks the delve like retiring
+    let hero = &game.observe().roster[0];
+    assert_eq!((hero.xp(), hero.delves()), (1, 1));
+
+    game.perform(&Command::Next).unwrap();
+    assert_eq!(game.observe().player, 1);
+    assert_ne!(*game.phase(), Phase::GameOver);
+}
@@ -0,0 +1,175 @@
+//! Reference client for the `--json` bot interface, driving the real binary
+//! over stdin/stdout the same way a bot in any other language would
+
+use serde_json::{json, Value};
+use std::{
+    env,
+    io::{BufRead, BufReader, Write},
+    path::PathBuf,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+struct Client {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    data_dir: PathBuf,
+    state: Value,
+}
+
+impl Client {
+    fn spawn(name: &str, seed: u64) -> Self {
+        // keeps the developer's own roster, history and config out of it
+        let data_dir = env::temp_dir().join(format!("dungeon-roll-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+
+        let mut child = Command::new(env!("CARGO_BIN_EXE_dungeon-roll"))
+            .args(["--json", "--seed", &seed.to_string(), "--data-dir"])
+            .arg(&data_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("could not start the game");
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+
+        let mut client = Self {
+            child,
+            stdin,
+            stdout,
+            data_dir,
+            state: Value::Null,
+        };
+        client.state = client.receive();
+        assert_eq!(client.state["type"], "state");
+        client
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut line = String::new();
+        self.stdout.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).expect("the game sent invalid JSON")
+    }
+
+    fn send_raw(&mut self, line: &str) -> Value {
+        writeln!(self.stdin, "{line}").unwrap();
+        self.stdin.flush().unwrap();
+        let response = self.receive();
+        if response["type"] == "state" {
+            self.state = response.clone();
+        }
+        response
+    }
+
+    fn send(&mut self, command: &Value) -> Value {
+        self.send_raw(&command.to_string())
+    }
+
+    fn phase(&self) -> &str {
+        self.state["observation"]["phase"].as_str().unwrap()
+    }
+
+    fn legal(&self) -> Vec<Value> {
+        self.state["legal"].as_array().unwrap().clone()
+    }
+
+    /// Turns a legal command template into a concrete move, picking the
+    /// smallest valid choice for the commands that select several dice
+    fn concrete(mut command: Value) -> Value {
+        match command["command"].as_str().unwrap() {
+            "reroll" => {
+                command["party"] = json!([]);
+            }
+            "quaff" => {
+                let graves = command["graves"].as_array().unwrap();
+                command["graves"] = json!([graves[0]]);
+            }
+            "dragon" => {
+                let allies = command["allies"].as_array().unwrap();
+                command["allies"] = json!(allies.iter().take(3).collect::<Vec<_>>());
+            }
+            _ => (),
+        }
+        command
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.data_dir);
+    }
+}
+
+#[test]
+fn starts_on_the_setup_screen() {
+    let client = Client::spawn("setup", 1);
+
+    assert_eq!(client.phase(), "Start");
+    assert!(client.legal().contains(&json!({"command": "start"})));
+    assert!(!client.state["observation"]["roster"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn malformed_lines_are_reported() {
+    let mut client = Client::spawn("malformed", 1);
+
+    let response = client.send_raw("fight 0 0");
+    assert_eq!(response["type"], "error");
+    assert_eq!(response["kind"], "malformed");
+
+    let response = client.send(&json!({"command": "dance"}));
+    assert_eq!(response["kind"], "malformed");
+}
+
+#[test]
+fn illegal_commands_leave_the_game_untouched() {
+    let mut client = Client::spawn("illegal", 1);
+    let before = client.state.clone();
+
+    let response = client.send(&json!({"command": "retire"}));
+    assert_eq!(response["type"], "error");
+    assert_eq!(response["kind"], "illegal");
+
+    assert_eq!(client.send_raw(""), before);
+}
+
+#[test]
+fn same_seed_same_game() {
+    let mut a = Client::spawn("seed-a", 42);
+    let mut b = Client::spawn("seed-b", 42);
+
+    let start = json!({"command": "start"});
+    assert_eq!(a.send(&start), b.send(&start));
+}
+
+#[test]
+fn legal_commands_are_accepted() {
+    let mut client = Client::spawn("legal", 7);
+    client.send(&json!({"command": "start"}));
+
+    for _ in 0..200 {
+        let legal = client.legal();
+        if legal.is_empty() {
+            break;
+        }
+
+        // always press on, so the bot ends up facing the dragon sooner or later
+        let command = legal
+            .iter()
+            .find(|c| c["command"] == "continue")
+            .unwrap_or(&legal[0])
+            .clone();
+        let response = client.send(&Client::concrete(command.clone()));
+        assert_eq!(
+            response["type"], "state",
+            "{command} was rejected: {response}"
+        );
+    }
+
+    assert_ne!(client.phase(), "Start");
+}
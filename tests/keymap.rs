@@ -0,0 +1,65 @@
+use crossterm::event::KeyCode;
+use dungeon_roll::keymap::{Control, Keymap, KeymapError};
+
+#[test]
+fn presets_round_trip() {
+    for name in Keymap::PRESETS {
+        let preset = Keymap::preset(name).unwrap();
+        preset.validate().unwrap();
+
+        let keymap = Keymap::parse(&preset.to_toml()).unwrap();
+        for control in Control::ALL {
+            assert_eq!(keymap.label(control), preset.label(control), "{name}");
+        }
+    }
+}
+
+#[test]
+fn bindings_replace_the_preset() {
+    let keymap = Keymap::parse(
+        r#"
+        preset = "vim"
+        [bindings]
+        quit = ["Esc", "x"]
+        back = ["Backspace"]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(keymap.control(KeyCode::Char('x')), Some(Control::Quit));
+    assert_eq!(keymap.control(KeyCode::Char('q')), None);
+    assert_eq!(keymap.control(KeyCode::Char('h')), Some(Control::Prev));
+    assert_eq!(keymap.label(Control::Quit), "Esc/x");
+}
+
+#[test]
+fn duplicate_bindings_are_rejected() {
+    let keymap = Keymap::parse(
+        r#"
+        preset = "vim"
+        [bindings]
+        quit = ["j"]
+        "#,
+    );
+
+    let Err(KeymapError::Invalid(reason)) = keymap else {
+        panic!("{keymap:?} was accepted");
+    };
+    assert_eq!(reason, "j is bound to both down and quit");
+}
+
+#[test]
+fn digits_are_kept_for_the_dice() {
+    let keymap = Keymap::parse("[bindings]\nconfirm = [\"Enter\", \"1\"]");
+
+    let Err(KeymapError::Invalid(reason)) = keymap else {
+        panic!("{keymap:?} was accepted");
+    };
+    assert_eq!(reason, "1 is bound to both a die and confirm");
+}
+
+#[test]
+fn every_control_needs_a_key() {
+    let keymap = Keymap::parse("[bindings]\nhelp = []");
+    assert!(matches!(keymap, Err(KeymapError::Invalid(_))));
+}
@@ -0,0 +1,211 @@
+use dungeon_roll::{
+    achievements::Achievement,
+    dice::{Ally, Dice, Monster, Render},
+    game::Action,
+    history::Ending,
+    locale::Language,
+    net::Entry,
+    scenario::Goal,
+};
+use std::{fs, path::Path};
+
+/// The `{name}` placeholders and `](die)` link targets in `text`, sorted
+fn markup(text: &str) -> Vec<String> {
+    let mut markup = Vec::new();
+    for (open, close) in [("{", "}"), ("](", ")")] {
+        let mut rest = text;
+        while let Some((_, after)) = rest.split_once(open) {
+            let (name, after) = after.split_once(close).unwrap();
+            markup.push(format!("{open}{name}{close}"));
+            rest = after;
+        }
+    }
+
+    markup.sort();
+    markup
+}
+
+#[test]
+fn every_key_exists_in_every_catalog() {
+    let english = Language::English.keys();
+    assert!(!english.is_empty());
+
+    for language in Language::ALL {
+        let keys = language.keys();
+        let missing = english
+            .iter()
+            .filter(|k| !keys.contains(k))
+            .collect::<Vec<_>>();
+        let extra = keys
+            .iter()
+            .filter(|k| !english.contains(k))
+            .collect::<Vec<_>>();
+        assert!(missing.is_empty(), "{} lacks {missing:?}", language.name());
+        assert!(
+            extra.is_empty(),
+            "{} has unknown {extra:?}",
+            language.name()
+        );
+    }
+}
+
+/// Every string literal in the sources under `dir` that looks like a dotted
+/// catalog key starting with one of `sections`
+fn quoted_keys(dir: &Path, sections: &[&str], keys: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            quoted_keys(&path, sections, keys);
+            continue;
+        }
+
+        let source = fs::read_to_string(&path).unwrap();
+        // every other piece is the inside of a literal, escaped quotes
+        // aside
+        for literal in source.split('"').skip(1).step_by(2) {
+            let is_key = literal.contains('.')
+                && literal
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.');
+            let section = literal.split('.').next().unwrap();
+            if is_key && sections.contains(&section) && !keys.iter().any(|k| k == literal) {
+                keys.push(literal.to_string());
+            }
+        }
+    }
+}
+
+/// Every string literal passed straight to `Language::text` or
+/// `Language::format` in the sources under `dir`
+fn literal_arguments(dir: &Path, literals: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            literal_arguments(&path, literals);
+            continue;
+        }
+
+        let source = fs::read_to_string(&path).unwrap();
+        for call in [".text(", ".format("] {
+            for (_, after) in source
+                .match_indices(call)
+                .map(|(i, _)| source.split_at(i + call.len()))
+            {
+                if let Some(literal) = after.trim_start().strip_prefix('"') {
+                    literals.push(literal.split('"').next().unwrap().to_string());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn every_key_used_exists() {
+    let english = Language::English.keys();
+    let mut sections = english
+        .iter()
+        .map(|k| k.split('.').next().unwrap())
+        .collect::<Vec<_>>();
+    sections.dedup();
+
+    let mut used = Vec::new();
+    quoted_keys(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &sections,
+        &mut used,
+    );
+    assert!(used.len() > 100, "only found {used:?}");
+
+    // sentences passed in place of a key would show up untranslated
+    let english_keys = english.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+    let mut literals = Vec::new();
+    literal_arguments(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &mut literals,
+    );
+    assert!(!literals.is_empty());
+    let unknown = literals
+        .iter()
+        .filter(|l| !english_keys.contains(l))
+        .collect::<Vec<_>>();
+    assert!(unknown.is_empty(), "{unknown:?} aren't keys");
+
+    for language in Language::ALL {
+        let keys = language.keys();
+        let missing = used
+            .iter()
+            .filter(|k| !keys.contains(&k.as_str()))
+            .collect::<Vec<_>>();
+        assert!(missing.is_empty(), "{} lacks {missing:?}", language.name());
+    }
+
+    // keys put together at runtime fall back to themselves when missing
+    for language in Language::ALL {
+        for achievement in Achievement::ALL {
+            assert!(!achievement.name(language).starts_with("achievement."));
+            assert!(!achievement.describe(language).starts_with("achievement."));
+        }
+        for ending in [
+            Ending::Retired,
+            Ending::Victory,
+            Ending::Monsters,
+            Ending::Dragon,
+        ] {
+            assert!(!ending.describe(language).starts_with("ending."));
+        }
+        for ally in (0..Ally::faces()).map(Ally::nth) {
+            assert!(!ally.name(language).starts_with("ally."));
+        }
+        for monster in (0..Monster::faces()).map(Monster::nth) {
+            assert!(!monster.name(language).starts_with("monster."));
+        }
+    }
+}
+
+#[test]
+fn translations_keep_placeholders_and_links() {
+    for language in Language::ALL {
+        for key in Language::English.keys() {
+            let english = markup(&Language::English.text(key));
+            let translated = markup(&language.text(key));
+            assert_eq!(english, translated, "{} {key}", language.name());
+        }
+    }
+}
+
+#[test]
+fn language_comes_from_the_locale() {
+    assert_eq!(
+        Language::from_locale("es_ES.UTF-8"),
+        Some(Language::Spanish)
+    );
+    assert_eq!(Language::from_locale("en_GB"), Some(Language::English));
+    assert_eq!(Language::from_locale("C"), None);
+}
+
+#[test]
+fn goals_and_log_entries_are_translated() {
+    let goal = Goal::SlayDragon {
+        max_allies: Some(2),
+    };
+    assert_eq!(
+        goal.describe(Language::English),
+        "Slay the dragon using at most 2 allies"
+    );
+    assert_eq!(
+        goal.describe(Language::Spanish),
+        "Mata al dragón usando como mucho 2 aliados"
+    );
+    assert_eq!(
+        Goal::ReachLevel { level: 5 }.describe(Language::Spanish),
+        "Llega al nivel 5"
+    );
+
+    let entry = Entry {
+        seat: 1,
+        action: Action::Select,
+        drawn: Vec::new(),
+    };
+    assert_eq!(entry.describe(Language::English), "P2 Select");
+    assert_eq!(entry.describe(Language::Spanish), "J2 Elegir");
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{press, tutorial};
+use crossterm::event::{Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use dungeon_roll::{
+    dice_source::ScriptedDice,
+    game::Game,
+    phase::{Monster, Phase},
+};
+use tui::{backend::TestBackend, style::Color, Terminal};
+
+/// Clicks the first die drawn as `symbol` in `color` on the playfield
+fn click(game: &mut Game<ScriptedDice>, symbol: &str, color: Color) {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    game.render(&mut terminal).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let (column, row) = (0..40)
+        .flat_map(|y| (0..72).map(move |x| (x, y)))
+        .find(|(x, y)| {
+            let cell = buffer.get(*x, *y);
+            cell.symbol == symbol && cell.fg == color
+        })
+        .unwrap();
+
+    game.handle_event(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }));
+}
+
+#[test]
+fn clicking_a_die_picks_it_then_confirms_it() {
+    let mut game = tutorial();
+    let thief = ("✂", Color::Rgb(128, 0, 128));
+    let fighter = ("⚔", Color::Green);
+    game.set_animations(false);
+    press(&mut game, KeyCode::Enter);
+
+    // the tutorial wants the Fighter, so confirming the Thief does nothing
+    click(&mut game, thief.0, thief.1);
+    click(&mut game, thief.0, thief.1);
+    assert_eq!(*game.phase(), Phase::Monster(Monster::SelectAlly));
+
+    click(&mut game, fighter.0, fighter.1);
+    assert_eq!(*game.phase(), Phase::Monster(Monster::SelectAlly));
+    click(&mut game, fighter.0, fighter.1);
+    assert_eq!(*game.phase(), Phase::Monster(Monster::SelectMonster));
+}
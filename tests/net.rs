@@ -0,0 +1,168 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use dungeon_roll::{
+    dice_source::DiceSource,
+    game::{Action, Game},
+    net::{
+        client::{self, Connection, Session},
+        server, ClientMessage, ServerMessage,
+    },
+    roster::Roster,
+    rules::Rules,
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+use std::{env, io, time::Duration};
+use tokio::{net::TcpListener, time::sleep};
+
+const SEED: u64 = 7;
+
+/// Both heroes join, the game starts and the first player looks around
+const ACTIONS: [Action; 7] = [
+    Action::Select,
+    Action::Next,
+    Action::Select,
+    Action::Confirm,
+    Action::Next,
+    Action::Next,
+    Action::Prev,
+];
+
+fn state<R: DiceSource>(game: &Game<R>) -> String {
+    serde_json::to_string(&game.observe()).unwrap()
+}
+
+/// Applies the next `n` actions the server sends
+async fn sync(conn: &mut Connection, session: &mut Session, n: usize) {
+    for _ in 0..n {
+        match conn.recv().await.unwrap().unwrap() {
+            ServerMessage::Action(entry) => session.apply(entry),
+            message => panic!("expected an action, got {message:?}"),
+        }
+    }
+}
+
+/// Starts a server on a free port, returning its address and where it keeps
+/// the roster
+async fn serve(name: &str) -> (String, std::path::PathBuf) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let roster_path =
+        env::temp_dir().join(format!("dungeon-roll-{name}-{}.toml", std::process::id()));
+    tokio::spawn(server::serve(
+        listener,
+        SEED,
+        Rules::default(),
+        Roster::default(),
+        roster_path.clone(),
+    ));
+
+    (addr, roster_path)
+}
+
+/// Takes `seat` back, waiting for the server to notice it was left
+async fn reconnect(addr: &str, seat: usize) -> (Connection, Session) {
+    for _ in 0..100 {
+        match client::connect(addr, Some(seat), false).await {
+            Ok(joined) => return joined,
+            Err(e) if e.kind() == io::ErrorKind::Other => sleep(Duration::from_millis(10)).await,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    panic!("seat {seat} was never freed");
+}
+
+#[tokio::test]
+async fn every_client_sees_the_same_game() {
+    let (addr, roster_path) = serve("net").await;
+
+    let (mut first, mut first_session) = client::connect(&addr, None, false).await.unwrap();
+    let (mut second, mut second_session) = client::connect(&addr, None, false).await.unwrap();
+    let (mut spectator, mut spectator_session) = client::connect(&addr, None, true).await.unwrap();
+    assert_eq!(first_session.seat(), Some(0));
+    assert_eq!(second_session.seat(), Some(1));
+    assert_eq!(spectator_session.seat(), None);
+
+    // the second player has to wait for the first to set the game up
+    second
+        .send(&ClientMessage::Action {
+            action: Action::Confirm,
+        })
+        .await
+        .unwrap();
+    assert!(matches!(
+        second.recv().await.unwrap(),
+        Some(ServerMessage::Rejected { .. })
+    ));
+
+    for action in ACTIONS {
+        first.send(&ClientMessage::Action { action }).await.unwrap();
+    }
+    sync(&mut first, &mut first_session, ACTIONS.len()).await;
+    sync(&mut second, &mut second_session, ACTIONS.len()).await;
+    sync(&mut spectator, &mut spectator_session, ACTIONS.len()).await;
+
+    let mut game = Game::new(
+        Pcg64Mcg::seed_from_u64(SEED),
+        Roster::default(),
+        Rules::default(),
+    );
+    for action in ACTIONS {
+        game.apply(action);
+    }
+    assert_eq!(game.observe().players.len(), 2);
+    for session in [&first_session, &second_session, &spectator_session] {
+        assert_eq!(state(session.game()), state(&game));
+    }
+
+    let _ = std::fs::remove_file(roster_path);
+}
+
+#[tokio::test]
+async fn players_take_their_seat_back() {
+    let (addr, roster_path) = serve("reconnect").await;
+
+    let (mut first, mut first_session) = client::connect(&addr, None, false).await.unwrap();
+    let (mut second, mut second_session) = client::connect(&addr, None, false).await.unwrap();
+    for action in ACTIONS {
+        first.send(&ClientMessage::Action { action }).await.unwrap();
+    }
+    sync(&mut first, &mut first_session, ACTIONS.len()).await;
+    sync(&mut second, &mut second_session, ACTIONS.len()).await;
+
+    drop(first);
+    let (mut first, mut first_session) = reconnect(&addr, 0).await;
+    assert_eq!(first_session.seat(), Some(0));
+    assert_eq!(state(first_session.game()), state(second_session.game()));
+
+    // the seat is still in play after coming back
+    first
+        .send(&ClientMessage::Action {
+            action: Action::Confirm,
+        })
+        .await
+        .unwrap();
+    sync(&mut first, &mut first_session, 1).await;
+    sync(&mut second, &mut second_session, 1).await;
+    assert_eq!(state(first_session.game()), state(second_session.game()));
+
+    let _ = std::fs::remove_file(roster_path);
+}
+
+#[test]
+fn help_is_kept_off_the_wire() {
+    let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let mut game = Game::new(
+        Pcg64Mcg::seed_from_u64(SEED),
+        Roster::default(),
+        Rules::default(),
+    );
+    let before = state(&game);
+
+    // the help turns its pages instead of picking another hero
+    assert!(game.handle_local(&key(KeyCode::Char('?'))));
+    assert!(game.handle_local(&key(KeyCode::Right)));
+    assert!(game.handle_local(&key(KeyCode::Char('?'))));
+    assert!(!game.handle_local(&key(KeyCode::Right)));
+    assert_eq!(state(&game), before);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::{dice, hero, press, start, tutorial};
+use crossterm::event::KeyCode;
+use dungeon_roll::{
+    dice::{Ally, Monster as Face},
+    phase::{Monster, Phase},
+    roster::Roster,
+    rules::{Mode, Rules},
+};
+
+#[test]
+fn number_keys_pick_dice() {
+    let mut game = tutorial();
+    press(&mut game, KeyCode::Enter);
+
+    // the Thief is the second die, but the tutorial wants the Fighter
+    press(&mut game, KeyCode::Char('2'));
+    press(&mut game, KeyCode::Enter);
+    assert_eq!(*game.phase(), Phase::Monster(Monster::SelectAlly));
+
+    press(&mut game, KeyCode::Char('1'));
+    press(&mut game, KeyCode::Enter);
+    assert_eq!(*game.phase(), Phase::Monster(Monster::SelectMonster));
+}
+
+#[test]
+fn zero_picks_the_tenth_die() {
+    let allies = (0..9).map(|_| Ally::Fighter).chain([Ally::Scroll]);
+    let dice = dice(allies, [Face::Goblin, Face::Goblin]);
+
+    // enough XP for a party of ten
+    let mut hero = hero(Mode::Campaign);
+    hero.add_xp(32);
+    let rules = Rules {
+        max_party_size: 10,
+        ..Rules::default()
+    };
+    let mut game = start(dice, Roster::from(vec![hero]), rules);
+    assert_eq!(game.observe().party.len(), 10);
+
+    press(&mut game, KeyCode::Char('0'));
+    press(&mut game, KeyCode::Enter);
+    assert!(matches!(
+        game.phase(),
+        Phase::Monster(Monster::SelectReroll(_))
+    ));
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::{dice, hero, press, start};
+use crossterm::event::KeyCode;
+use dungeon_roll::{
+    game::Game,
+    locale::Language,
+    roster::Roster,
+    rules::{Mode, Rules, RulesError, PARTY_SIZE_LIMIT},
+};
+use tui::{backend::TestBackend, Terminal};
+
+fn check(rules: &Rules) -> Result<(), String> {
+    rules.validate().map_err(|e| match e {
+        RulesError::Invalid(reason) => reason.to_string(),
+        e => panic!("{e}"),
+    })
+}
+
+#[test]
+fn sensible_rules_are_accepted() {
+    assert_eq!(check(&Rules::default()), Ok(()));
+    assert_eq!(
+        check(&Rules {
+            max_party_size: 3,
+            max_dungeon_size: 1,
+            dragon_threshold: 1,
+            ..Rules::default()
+        }),
+        Ok(())
+    );
+    assert_eq!(
+        check(&Rules {
+            dragon_threshold: 10,
+            ..Rules::default()
+        }),
+        Ok(())
+    );
+}
+
+#[test]
+fn unplayable_rules_are_rejected() {
+    assert_eq!(
+        check(&Rules {
+            max_party_size: 2,
+            ..Rules::default()
+        }),
+        Err("max_party_size must be at least 3".to_string())
+    );
+    assert_eq!(
+        check(&Rules {
+            max_party_size: PARTY_SIZE_LIMIT + 1,
+            ..Rules::default()
+        }),
+        Err("max_party_size must be at most 20".to_string())
+    );
+    assert_eq!(
+        check(&Rules {
+            max_dungeon_size: 0,
+            dragon_threshold: 0,
+            ..Rules::default()
+        }),
+        Err("max_dungeon_size must be at least 1".to_string())
+    );
+
+    let threshold = "dragon_threshold must be between 1 and max_dungeon_size".to_string();
+    assert_eq!(
+        check(&Rules {
+            dragon_threshold: 0,
+            ..Rules::default()
+        }),
+        Err(threshold.clone())
+    );
+    assert_eq!(
+        check(&Rules {
+            dragon_threshold: 11,
+            ..Rules::default()
+        }),
+        Err(threshold)
+    );
+}
+
+#[test]
+fn classic_parties_ignore_the_cap() {
+    let rules = Rules {
+        max_party_size: 4,
+        ..Rules::default()
+    };
+
+    let game = start(dice([], []), Roster::from(vec![hero(Mode::Classic)]), rules);
+    assert_eq!(game.observe().party.len(), 7);
+}
+
+#[test]
+fn the_help_lists_every_campaign_party_size() {
+    let rules = Rules {
+        max_party_size: PARTY_SIZE_LIMIT,
+        ..Rules::default()
+    };
+    let mut game = Game::new(dice([], []), Roster::default(), rules);
+    game.set_language(Language::English);
+    for code in [
+        KeyCode::Char('?'),
+        KeyCode::Right,
+        KeyCode::Right,
+        KeyCode::Right,
+    ] {
+        press(&mut game, code);
+    }
+
+    let mut terminal = Terminal::new(TestBackend::new(120, 60)).unwrap();
+    game.render(&mut terminal).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text = (0..60)
+        .map(|y| {
+            (0..120)
+                .map(|x| buffer.get(x, y).symbol.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(text.contains("0 XP: 4 allies"), "{text}");
+    assert!(text.contains("XP: 20 allies"), "{text}");
+}
@@ -0,0 +1,66 @@
+use dungeon_roll::{
+    game::{Command, Game},
+    rules::Rules,
+    scenario::{GoalStatus, Scenario, ScenarioError},
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+
+fn goblin_ambush() -> Game<Pcg64Mcg> {
+    let scenario = Scenario::load(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/scenarios/goblin_ambush.toml"),
+        &Rules::default(),
+    )
+    .unwrap();
+    Game::with_scenario(Pcg64Mcg::seed_from_u64(0), Rules::default(), scenario)
+}
+
+fn fight(game: &mut Game<Pcg64Mcg>, ally: usize, monster: usize) {
+    game.perform(&Command::Fight { ally, monster }).unwrap();
+}
+
+#[test]
+fn solved_with_two_allies() {
+    let mut game = goblin_ambush();
+    fight(&mut game, 0, 0); // the fighter slays every goblin
+    fight(&mut game, 1, 0); // the cleric slays every skeleton
+    game.perform(&Command::Skip).unwrap();
+
+    assert_eq!(game.goal().unwrap().1, GoalStatus::Solved);
+}
+
+#[test]
+fn failed_with_three_allies() {
+    let mut game = goblin_ambush();
+    fight(&mut game, 3, 0);
+    fight(&mut game, 0, 0);
+    assert_eq!(game.goal().unwrap().1, GoalStatus::Pending);
+    fight(&mut game, 0, 0);
+
+    assert_eq!(game.goal().unwrap().1, GoalStatus::Failed);
+}
+
+#[test]
+fn the_dragon_needs_enough_dragons() {
+    let scenario: Scenario = toml::from_str(
+        r#"
+        phase = "dragon"
+        party = ["Fighter", "Mage", "Cleric"]
+        dungeon = ["Dragon", "Dragon"]
+
+        [goal]
+        kind = "slay_dragon"
+        "#,
+    )
+    .unwrap();
+
+    let error = scenario.validate(&Rules::default()).unwrap_err();
+    assert!(matches!(error, ScenarioError::DragonThreshold(3)));
+    assert!(error.to_string().contains('3'), "{error}");
+
+    let rules = Rules {
+        dragon_threshold: 2,
+        ..Rules::default()
+    };
+    assert!(scenario.validate(&rules).is_ok());
+}
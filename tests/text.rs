@@ -0,0 +1,63 @@
+use dungeon_roll::{
+    achievements::Achievements,
+    game::Game,
+    hero::{Hero, Type},
+    history::{History, Sort},
+    locale::Language,
+    roster::Roster,
+    rules::Rules,
+    saves::Saves,
+    text,
+};
+use rand::SeedableRng;
+use rand_pcg::Pcg64Mcg;
+use std::{env, fs};
+
+const DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/text");
+
+#[test]
+fn transcript() {
+    let input = fs::read_to_string(format!("{DIR}/session.in")).unwrap();
+    let expected = fs::read_to_string(format!("{DIR}/session.out")).unwrap();
+
+    let mut game = Game::new(
+        Pcg64Mcg::seed_from_u64(3),
+        Roster::default(),
+        Rules::default(),
+    );
+    game.track_history(History::default(), 3);
+    game.track_achievements(Achievements::default());
+    let path = |file: &str| {
+        env::temp_dir().join(format!(
+            "dungeon-roll-text-{}-{file}.toml",
+            std::process::id()
+        ))
+    };
+    let saves = Saves {
+        roster: path("roster"),
+        history: path("history"),
+        achievements: path("achievements"),
+    };
+    let mut output = Vec::new();
+    text::play(game, input.as_bytes(), &mut output, &saves).unwrap();
+
+    // the retired delve is kept like in the terminal game
+    let history = History::load(&saves.history).unwrap();
+    for file in [&saves.roster, &saves.history, &saves.achievements] {
+        let _ = fs::remove_file(file);
+    }
+
+    assert_eq!(String::from_utf8(output).unwrap(), expected);
+    assert_eq!(history.leaderboard(Sort::Recent, None).len(), 1);
+}
+
+#[test]
+fn heroes_named_after_their_type() {
+    // rosters saved before heroes had names of their own
+    let hero = Hero::new("Bard".to_string(), Type::Bard);
+    assert_eq!(hero.title(Language::English), "Bard");
+    assert_eq!(hero.title(Language::Spanish), "Bardo");
+    let hero = Hero::new("Lyra".to_string(), Type::Bard);
+    assert_eq!(hero.title(Language::English), "Lyra the Bard");
+    assert_eq!(hero.title(Language::Spanish), "Lyra el Bardo");
+}
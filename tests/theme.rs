@@ -0,0 +1,87 @@
+use dungeon_roll::{
+    dice::{Ally, Monster},
+    theme::Theme,
+};
+use tui::style::Style;
+
+const ALLIES: [Ally; 6] = [
+    Ally::Fighter,
+    Ally::Cleric,
+    Ally::Mage,
+    Ally::Champion,
+    Ally::Thief,
+    Ally::Scroll,
+];
+
+const MONSTERS: [Monster; 6] = [
+    Monster::Goblin,
+    Monster::Skeleton,
+    Monster::Ooze,
+    Monster::Dragon,
+    Monster::Chest,
+    Monster::Potion,
+];
+
+/// The style of every face in `theme`, allies first
+fn faces(theme: Theme) -> Vec<(String, Style)> {
+    let allies = ALLIES.iter().map(|a| (format!("{a:?}"), theme.ally(a)));
+    let monsters = MONSTERS
+        .iter()
+        .map(|m| (format!("{m:?}"), theme.monster(m)));
+    allies.chain(monsters).collect()
+}
+
+#[test]
+fn faces_can_be_told_apart() {
+    for theme in [Theme::Colorblind, Theme::Monochrome] {
+        let faces = faces(theme);
+        for (i, (a, style)) in faces.iter().enumerate() {
+            for (b, other) in &faces[i + 1..] {
+                assert_ne!(style, other, "{} {a} {b}", theme.name());
+            }
+        }
+    }
+}
+
+#[test]
+fn monsters_leave_the_allies_their_colors() {
+    for ally in &ALLIES {
+        for monster in &MONSTERS {
+            assert_ne!(
+                Theme::Colorblind.ally(ally).fg,
+                Theme::Colorblind.monster(monster).fg,
+                "{ally:?} {monster:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn the_cursor_hides_no_face() {
+    for theme in Theme::ALL {
+        for (face, style) in faces(theme) {
+            assert!(
+                style.fg.is_none() || style.fg != theme.cursor().bg,
+                "{} {face}",
+                theme.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn selections_show_on_every_face() {
+    for theme in Theme::ALL {
+        for (face, style) in faces(theme) {
+            let idle = theme.idle().patch(style);
+            let selected = theme.selected().patch(style);
+            let confirmed = theme.confirmed().patch(style);
+            let cursor = theme.cursor().patch(style);
+            assert_ne!(idle, selected, "{} {face}", theme.name());
+            assert_ne!(idle, confirmed, "{} {face}", theme.name());
+            assert_ne!(selected, confirmed, "{} {face}", theme.name());
+            assert_ne!(cursor, selected, "{} {face}", theme.name());
+            assert_ne!(cursor, idle, "{} {face}", theme.name());
+        }
+    }
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use common::{press, tutorial};
+use crossterm::event::KeyCode;
+use dungeon_roll::{
+    dice_source::ScriptedDice,
+    game::Game,
+    keymap::Keymap,
+    locale::Language,
+    phase::{Dragon, Loot, Monster, Phase, Regroup, Reroll},
+};
+
+/// Plays the tutorial until `done`, like a player who only knows which keys
+/// finish a step and otherwise sweeps the cursor back and forth, passing
+/// every phase it sees to `seen`
+fn play_until(
+    game: &mut Game<ScriptedDice>,
+    done: impl Fn(&Phase) -> bool,
+    mut seen: impl FnMut(&Phase),
+) {
+    let mut stuck = 0;
+    for _ in 0..400 {
+        seen(game.phase());
+        if done(game.phase()) {
+            return;
+        }
+
+        let prompt = game.tutorial_prompt();
+        for code in [
+            KeyCode::Enter,
+            KeyCode::Char(' '),
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Esc,
+        ] {
+            press(game, code);
+            seen(game.phase());
+            if game.tutorial_prompt() != prompt {
+                break;
+            }
+        }
+        if game.tutorial_prompt() == prompt {
+            stuck += 1;
+            let sweep = if stuck / 8 % 2 == 0 {
+                KeyCode::Right
+            } else {
+                KeyCode::Left
+            };
+            press(game, sweep);
+        } else {
+            stuck = 0;
+        }
+    }
+}
+
+#[test]
+fn unexpected_keys_are_ignored() {
+    let mut game = tutorial();
+    let prompt = game.tutorial_prompt();
+
+    for code in [KeyCode::Esc, KeyCode::Up, KeyCode::Down, KeyCode::Char(' ')] {
+        press(&mut game, code);
+    }
+
+    assert_eq!(*game.phase(), Phase::Start);
+    assert_eq!(game.tutorial_prompt(), prompt);
+}
+
+#[test]
+fn ends_slaying_the_dragon() {
+    let mut game = tutorial();
+    play_until(&mut game, |p| *p == Phase::Victory, |_| ());
+    assert_eq!(*game.phase(), Phase::Victory);
+
+    press(&mut game, KeyCode::Enter);
+    assert_eq!(*game.phase(), Phase::GameOver);
+    assert!(game.tutorial_prompt().unwrap().contains('q'));
+}
+
+#[test]
+fn visits_every_phase() {
+    let mut game = tutorial();
+    let mut seen = Vec::new();
+    play_until(
+        &mut game,
+        |p| *p == Phase::GameOver,
+        |p| {
+            if !seen.contains(&format!("{p:?}")) {
+                seen.push(format!("{p:?}"));
+            }
+        },
+    );
+
+    // setting up a level or a delve never waits on the player, and the
+    // scoreboard is only shown between the turns of a hot-seat game
+    let phases = [
+        Phase::Start,
+        Phase::Monster(Monster::SelectAlly),
+        Phase::Monster(Monster::SelectReroll(Reroll::Ally)),
+        Phase::Monster(Monster::SelectReroll(Reroll::Monster)),
+        Phase::Monster(Monster::ConfirmReroll),
+        Phase::Monster(Monster::SelectMonster),
+        Phase::Monster(Monster::ConfirmCombat),
+        Phase::Monster(Monster::Defeat),
+        Phase::Loot(Loot::SelectAlly),
+        Phase::Loot(Loot::SelectLoot),
+        Phase::Loot(Loot::ConfirmLoot),
+        Phase::Loot(Loot::SelectGraveyard),
+        Phase::Loot(Loot::ConfirmGraveyard),
+        Phase::Dragon(Dragon::SelectAlly),
+        Phase::Dragon(Dragon::Confirm),
+        Phase::Dragon(Dragon::Defeat),
+        Phase::EmptyDungeon,
+        Phase::Regroup(Regroup::Continue),
+        Phase::Regroup(Regroup::End),
+        Phase::Victory,
+        Phase::GameOver,
+    ];
+    for phase in phases {
+        assert!(
+            seen.contains(&format!("{phase:?}")),
+            "{phase:?} was skipped"
+        );
+    }
+}
+
+#[test]
+fn prompts_show_the_active_keys() {
+    let mut game = tutorial();
+    assert!(game.tutorial_prompt().unwrap().contains("Enter"));
+
+    game.set_keymap(Keymap::preset("vim").unwrap());
+    press(&mut game, KeyCode::Enter);
+    let prompt = game.tutorial_prompt().unwrap();
+    assert!(prompt.contains('h') && prompt.contains('l'), "{prompt}");
+    assert!(!prompt.contains('{'), "{prompt}");
+}
+
+#[test]
+fn prompts_are_translated() {
+    let mut game = tutorial();
+    game.set_language(Language::Spanish);
+    let prompt = game.tutorial_prompt().unwrap();
+    assert!(prompt.starts_with("Este es el héroe"), "{prompt}");
+
+    play_until(&mut game, |p| *p == Phase::GameOver, |_| ());
+    let prompt = game.tutorial_prompt().unwrap();
+    assert!(prompt.starts_with("Eso es todo"), "{prompt}");
+}